@@ -0,0 +1,95 @@
+//! Implementation of the `derive_fieldwise` attribute.
+//!
+//! The attribute implements the given template implementations for a struct, while the fields of
+//! the struct are treated like the elements of a tuple. So, the same `for_tuples!` syntax as in the
+//! semi-automatic mode can be used to implement a trait field by field.
+
+use crate::semi_automatic::semi_automatic_fields_impl;
+
+use proc_macro2::{Span, TokenStream};
+
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned,
+    Error, Fields, Ident, ItemImpl, ItemStruct, Member, Result,
+};
+
+use quote::quote;
+
+/// The template implementations given as arguments to `#[derive_fieldwise(..)]`.
+pub struct Templates(Vec<ItemImpl>);
+
+impl Parse for Templates {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut templates = Vec::new();
+
+        while !input.is_empty() {
+            templates.push(input.parse()?);
+        }
+
+        if templates.is_empty() {
+            Err(Error::new(
+                Span::call_site(),
+                "Expected at least one template implementation, e.g. \
+                 `#[derive_fieldwise(impl Trait for Fields { .. })]`.",
+            ))
+        } else {
+            Ok(Self(templates))
+        }
+    }
+}
+
+/// Generate the implementations of the given `templates` for `item`.
+///
+/// As the placeholder is replaced by an ident, we generate a type alias for each field type.
+pub fn fieldwise_impl(templates: Templates, item: ItemStruct) -> Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "Generic structs are not supported by `derive_fieldwise`.",
+        ));
+    }
+
+    if item.fields.is_empty() {
+        return Err(Error::new(
+            item.span(),
+            "Expected a struct with at least one field.",
+        ));
+    }
+
+    let ident = &item.ident;
+    let members = match &item.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|f| f.ident.clone().map(Member::Named))
+            .collect::<Vec<_>>(),
+        fields => (0..fields.len()).map(Member::from).collect(),
+    };
+    let aliases = (0..item.fields.len())
+        .map(|i| Ident::new(&format!("__{}Field{}", ident, i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let field_types = item.fields.iter().map(|f| &f.ty);
+
+    let mut res = quote! {
+        #item
+
+        #(
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            type #aliases = #field_types;
+        )*
+    };
+
+    for template in &templates.0 {
+        res.extend(semi_automatic_fields_impl(
+            template,
+            parse_quote!(#ident),
+            &aliases,
+            members.clone(),
+        )?);
+    }
+
+    Ok(res)
+}
@@ -4,7 +4,7 @@
 //! tuples. This has some limitations, as no support for associated types, consts, return values or
 //! functions with a default implementation.
 
-use crate::utils::add_tuple_element_generics;
+use crate::utils::{add_tuple_element_generics, check_std_tuple_bounds};
 
 use proc_macro2::{Span, TokenStream};
 
@@ -14,22 +14,28 @@ use syn::{
     parse_quote,
     spanned::Spanned,
     visit::{self, Visit},
-    Error, FnArg, Generics, Ident, Index, ItemTrait, Pat, Result, ReturnType, Signature, TraitItem,
-    TraitItemFn, Type,
+    Error, FnArg, GenericParam, Generics, Ident, Index, ItemTrait, Pat, Result, ReturnType,
+    Signature, TraitBoundModifier, TraitItem, TraitItemFn, Type, TypeImplTrait, TypeParamBound,
+    TypePath, WherePredicate,
 };
 
-use quote::quote;
+use quote::{format_ident, quote};
 
 /// Generate the full-automatic tuple implementations for a given trait definition and the given tuples.
 pub fn full_automatic_impl(
     definition: ItemTrait,
     tuple_elements: Vec<Ident>,
-    min: Option<usize>,
+    arities: &[usize],
 ) -> Result<TokenStream> {
     check_trait_declaration(&definition)?;
+    check_std_tuple_bounds(
+        self_bounds(&definition),
+        arities.iter().copied().max().unwrap_or(0),
+    )?;
 
-    let impls = (min.unwrap_or(0)..=tuple_elements.len())
-        .map(|i| generate_tuple_impl(&definition, &tuple_elements[0..i]));
+    let impls = arities
+        .iter()
+        .map(|&i| generate_tuple_impl(&definition, &tuple_elements[0..i]));
 
     Ok(quote!(
         #definition
@@ -37,6 +43,309 @@ pub fn full_automatic_impl(
     ))
 }
 
+/// Returns the super traits and the bounds of `where Self: ..` of the given trait definition.
+fn self_bounds(definition: &ItemTrait) -> impl Iterator<Item = &TypeParamBound> {
+    let where_bounds = definition
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .filter_map(|p| match p {
+            WherePredicate::Type(p) if p.bounded_ty == parse_quote!(Self) => Some(&p.bounds),
+            _ => None,
+        })
+        .flatten();
+
+    definition.supertraits.iter().chain(where_bounds)
+}
+
+/// Generate the `dyn Trait` views for a given trait definition and the given tuples.
+///
+/// The views are provided by a separate trait, as the generic associated types would make the
+/// given trait not object safe.
+pub fn dyn_views_impl(
+    definition: &ItemTrait,
+    tuple_elements: &[Ident],
+    arities: &[usize],
+) -> Result<TokenStream> {
+    if !definition.generics.params.is_empty() {
+        return Err(Error::new(
+            definition.generics.span(),
+            "Generic traits are not supported by `dyn_views`.",
+        ));
+    }
+
+    check_object_safety(definition)?;
+
+    let vis = &definition.vis;
+    let name = &definition.ident;
+    let views = format_ident!("{}AsDyn", name);
+    let doc = format!(
+        "Provides views of the elements of a tuple as `dyn {}`.",
+        name
+    );
+
+    let impls = arities.iter().map(|&i| {
+        let tuple_elements = &tuple_elements[0..i];
+        let len = Index::from(i);
+        let index = (0..i).map(Index::from);
+        let index_mut = index.clone();
+
+        quote!(
+            #[allow(unused)]
+            impl< #( #tuple_elements: #name ),* > #views for ( #( #tuple_elements, )* ) {
+                const LEN: usize = #len;
+                type Dyn<'a> = [&'a (dyn #name + 'a); #len] where Self: 'a;
+                type DynMut<'a> = [&'a mut (dyn #name + 'a); #len] where Self: 'a;
+
+                fn as_dyn(&self) -> Self::Dyn<'_> {
+                    [ #( &self.#index ),* ]
+                }
+
+                fn as_dyn_mut(&mut self) -> Self::DynMut<'_> {
+                    [ #( &mut self.#index_mut ),* ]
+                }
+            }
+        )
+    });
+
+    Ok(quote!(
+        #[doc = #doc]
+        #vis trait #views {
+            /// The number of elements.
+            const LEN: usize;
+            /// An array of references to the elements.
+            type Dyn<'a>: AsRef<[&'a (dyn #name + 'a)]> + IntoIterator<Item = &'a (dyn #name + 'a)>
+            where
+                Self: 'a;
+            /// An array of mutable references to the elements.
+            type DynMut<'a>: AsMut<[&'a mut (dyn #name + 'a)]>
+                + IntoIterator<Item = &'a mut (dyn #name + 'a)>
+            where
+                Self: 'a;
+
+            /// Returns references to all elements as `dyn Trait`.
+            fn as_dyn(&self) -> Self::Dyn<'_>;
+
+            /// Returns mutable references to all elements as `dyn Trait`.
+            fn as_dyn_mut(&mut self) -> Self::DynMut<'_>;
+        }
+
+        #( #impls )*
+    ))
+}
+
+/// Checks that the given trait definition can be used as `dyn Trait`.
+///
+/// Methods with a `where Self: Sized` bound are not callable on a trait object and are ignored.
+fn check_object_safety(definition: &ItemTrait) -> Result<()> {
+    let error = |span: Span, reason: &str| {
+        Error::new(
+            span,
+            format!(
+                "`dyn_views` requires a trait that can be used as `dyn {}`, but {}.",
+                definition.ident, reason
+            ),
+        )
+    };
+    let mut errors = Vec::new();
+
+    if let Some(sized) = self_bounds(definition).find(|b| is_sized_bound(b)) {
+        errors.push(error(sized.span(), "it requires `Self: Sized`"));
+    }
+
+    let methods = definition.items.iter().filter_map(|i| match i {
+        TraitItem::Fn(f) => Some(&f.sig),
+        _ => None,
+    });
+    for sig in methods.filter(|sig| !requires_sized_self(sig)) {
+        if !matches!(sig.inputs.first(), Some(FnArg::Receiver(_))) {
+            errors.push(error(
+                sig.ident.span(),
+                "methods without `self` receiver are not supported",
+            ));
+        } else if let Some(param) = sig
+            .generics
+            .params
+            .iter()
+            .find(|p| !matches!(p, GenericParam::Lifetime(_)))
+        {
+            errors.push(error(param.span(), "generic methods are not supported"));
+        } else {
+            let mut visitor = FindUnsupportedTypes::default();
+            sig.inputs
+                .iter()
+                .skip(1)
+                .for_each(|arg| visitor.visit_fn_arg(arg));
+            visitor.visit_return_type(&sig.output);
+
+            if let Some(span) = visitor.self_type {
+                errors.push(error(
+                    span,
+                    "`Self` is not supported in arguments or return types",
+                ));
+            }
+
+            if let Some(span) = visitor.impl_trait {
+                errors.push(error(
+                    span,
+                    "`impl Trait` is not supported in arguments or return types",
+                ));
+            }
+        }
+    }
+
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        Some(first) => Err(errors.fold(first, |mut e, n| {
+            e.combine(n);
+            e
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Returns `true` if the given bound is `Sized`, e.g. `Sized` or `core::marker::Sized`.
+fn is_sized_bound(bound: &TypeParamBound) -> bool {
+    let TypeParamBound::Trait(bound) = bound else {
+        return false;
+    };
+    let segments = &bound.path.segments;
+
+    // `?Sized` removes the bound.
+    matches!(bound.modifier, TraitBoundModifier::None)
+        && segments.last().is_some_and(|s| s.ident == "Sized")
+        && (segments.len() == 1 || ["core", "std"].iter().any(|c| segments[0].ident == c))
+}
+
+/// Returns `true` if the given method has a `where Self: Sized` bound.
+fn requires_sized_self(sig: &Signature) -> bool {
+    sig.generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .any(|p| {
+            matches!(
+                p,
+                WherePredicate::Type(p)
+                    if matches!(&p.bounded_ty, Type::Path(t) if t.path.is_ident("Self"))
+                        && p.bounds.iter().any(is_sized_bound)
+            )
+        })
+}
+
+/// Finds the first usage of the `Self` type and of an `impl Trait` type.
+#[derive(Default)]
+struct FindUnsupportedTypes {
+    self_type: Option<Span>,
+    impl_trait: Option<Span>,
+}
+
+impl<'ast> Visit<'ast> for FindUnsupportedTypes {
+    fn visit_type_path(&mut self, ty: &'ast TypePath) {
+        match ty.path.segments.first() {
+            Some(segment) if segment.ident == "Self" && ty.path.segments.len() == 1 => {
+                self.self_type.get_or_insert(segment.ident.span());
+            }
+            _ => visit::visit_type_path(self, ty),
+        }
+    }
+
+    fn visit_type_impl_trait(&mut self, ty: &'ast TypeImplTrait) {
+        self.impl_trait.get_or_insert(ty.impl_token.span);
+        visit::visit_type_impl_trait(self, ty);
+    }
+}
+
+/// Generate the enums that dispatch to one of their variants for a given trait definition.
+///
+/// For each number of tuple elements, an enum with one variant per tuple element is generated and
+/// the trait is implemented by calling the trait method of the active variant.
+pub fn enum_dispatch_impl(
+    definition: &ItemTrait,
+    tuple_elements: &[Ident],
+    arities: &[usize],
+) -> Result<TokenStream> {
+    // There is no active variant to dispatch a static method to.
+    if let Some(f) = definition.items.iter().find_map(|i| match i {
+        TraitItem::Fn(f) if !matches!(f.sig.inputs.first(), Some(FnArg::Receiver(_))) => Some(f),
+        _ => None,
+    }) {
+        return Err(Error::new(
+            f.sig.ident.span(),
+            "`enum_dispatch` requires all methods to have a `self` receiver.",
+        ));
+    }
+
+    let vis = &definition.vis;
+    let name = &definition.ident;
+    let unsafety = &definition.unsafety;
+    let ty_generics = definition.generics.split_for_impl().1;
+
+    let enums = arities.iter().filter(|&&i| i > 0).map(|&i| {
+        let tuple_elements = &tuple_elements[0..i];
+        let enum_name = format_ident!("{}Enum{}", name, i);
+        let variants = (0..i)
+            .map(|i| format_ident!("Element{}", i))
+            .collect::<Vec<_>>();
+        let doc = format!(
+            "Holds one of {} values and implements [`{}`] by dispatching to the active one.",
+            i, name,
+        );
+
+        // Only one variant is called, so the arguments don't need to implement `Clone`.
+        let mut generics = definition.generics.clone();
+        add_tuple_element_generics(
+            tuple_elements,
+            Some(quote!(#name #ty_generics)),
+            &mut generics,
+        );
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let fns = definition.items.iter().filter_map(|i| match i {
+            TraitItem::Fn(f) => Some(generate_dispatch_method(f, &variants)),
+            _ => None,
+        });
+
+        quote!(
+            #[doc = #doc]
+            #vis enum #enum_name< #( #tuple_elements ),* > {
+                #( #variants(#tuple_elements), )*
+            }
+
+            #[allow(unused)]
+            #unsafety impl #impl_generics #name #ty_generics for #enum_name< #( #tuple_elements ),* >
+                #where_clause
+            {
+                #( #fns )*
+            }
+        )
+    });
+
+    Ok(quote!( #( #enums )* ))
+}
+
+/// Generate a method that calls `method` of the active variant.
+fn generate_dispatch_method(method: &TraitItemFn, variants: &[Ident]) -> TokenStream {
+    let name = &method.sig.ident;
+    let (sig, arg_names, _) = update_signature_and_extract_arg_infos(method.sig.clone());
+    let arg_names = repeat(&arg_names);
+
+    let mut res = method.clone();
+    res.sig = sig;
+    res.semi_token = None;
+    res.default = Some(parse_quote!({
+        match self {
+            #(
+                Self::#variants(active_tuple_element) => {
+                    active_tuple_element.#name( #( #arg_names ),* )
+                }
+            )*
+        }
+    }));
+
+    quote!( #res )
+}
+
 fn check_trait_declaration(trait_decl: &ItemTrait) -> Result<()> {
     let mut visitor = CheckTraitDeclaration { errors: Vec::new() };
     visit::visit_item_trait(&mut visitor, trait_decl);
@@ -154,10 +463,7 @@ fn generate_delegate_method(method: &TraitItemFn, tuple_elements: &[Ident]) -> T
         .sig
         .inputs
         .first()
-        .map(|a| match a {
-            FnArg::Receiver(_) => true,
-            _ => false,
-        })
+        .map(|a| matches!(a, FnArg::Receiver(_)))
         .unwrap_or(false);
     let (sig, arg_names, arg_clones) = update_signature_and_extract_arg_infos(method.sig.clone());
     let arg_names_per_tuple = repeat(&arg_names);
@@ -227,8 +533,5 @@ fn update_signature_and_extract_arg_infos(
 }
 
 fn is_reference_type(ty: &Type) -> bool {
-    match ty {
-        Type::Reference(_) => true,
-        _ => false,
-    }
+    matches!(ty, Type::Reference(_))
 }
@@ -32,6 +32,32 @@ The attribute macro can be called with one `#[impl_for_tuples(5)]` or with two `
 parameters. The former instructs the macro to generate up to a tuple of five elements and the later instructs it
 to generate from a tuple with two element up to five elements.
 
+Instead of the number of tuple elements, `#[impl_for_tuples(recursive)]` can be given. In this
+mode the trait is only implemented for `()` and `(Head, Tail)`, where `Tail` implements the trait
+as well. So, nested tuples of any depth like `(A, (B, (C, ())))` are supported by just two
+implementations, which keeps the compile time low for a large number of elements. Flat tuples like
+`(A, B, C)` are not implemented in this mode, so the elements need to be given as such a nested
+list, where each tuple contains one element and the rest of the list, ending with `()`.
+
+In the full-automatic mode, `dyn_views` can be given after the number of tuple elements, e.g.
+`#[impl_for_tuples(5, dyn_views)]`, to generate views of the tuple elements as trait objects.
+For an object safe trait `Plugin`, the trait `PluginAsDyn` is generated and implemented for the
+tuples. It provides `fn as_dyn(&self) -> Self::Dyn<'_>` and
+`fn as_dyn_mut(&mut self) -> Self::DynMut<'_>`. For a tuple with `N` elements, the generic
+associated types `Dyn` and `DynMut` are the arrays `[&dyn Plugin; N]` and `[&mut dyn Plugin; N]`,
+while the trait only guarantees that they can be iterated or used as slice. The number of elements
+is provided separately as `const LEN: usize`. Traits that require `Self: Sized`, as super trait or
+by `where Self: Sized`, are rejected. The same applies to traits with generic methods, methods
+without `self` receiver or methods with `Self` or `impl Trait` in arguments or return types,
+unless the method has a `where Self: Sized` bound.
+
+In the full-automatic mode, `enum_dispatch` can also be given after the number of tuple elements,
+e.g. `#[impl_for_tuples(5, enum_dispatch)]`. For a trait `Plugin`, this generates the enums
+`PluginEnum1<T0>` up to `PluginEnum5<T0, .., T4>` with one variant per element, `Element0`,
+`Element1`, etc. The trait is implemented for the enums by calling the method of the active
+variant. So, the tuple implementations call all elements, while the enums call one element. All
+methods of the trait need to have a `self` receiver.
+
 ### Semi-automatic syntax
 
 ```
@@ -77,8 +103,96 @@ The given example shows all supported combinations of `for_tuples!`. When access
 The placeholder tuple identifer is taken from the self type given to the implementation block. So, it
 is up to the user to chose any valid identifier.
 
+The same works for any other expression that is a tuple with the same number of elements, e.g.
+`state.Tuple`, `state.inner.Tuple`, `(*ptr).Tuple` or `args.0.Tuple`. This can be used to zip `self`
+with another tuple, e.g. `#( Tuple.run(&mut state.Tuple); )*`. Inside a repetition `#index` is
+replaced by the index of the current tuple element, e.g. to index into an array with `array[#index]`.
+
+`for_tuples!` can also be used at a pattern position to destructure a tuple, e.g.
+`let for_tuples!( ( #( Tuple ),* ) ) = self;` or `match value { for_tuples!( ( #( Tuple ),* ) ) => .. }`.
+Each element is bound to a generated name, that can be accessed by using the placeholder
+(`Tuple`) as a value in a following repetition, e.g. `Trait::method(Tuple)`. The generated name is
+distinct from the name of the element type, so `Tuple::method()` still refers to the type. To call
+methods on the bound elements, bind them to a derived identifier like `Tuple_value` as
+`Tuple.method()` always accesses `self`.
+
 The separator given to `#( Tuple::something() )SEPARATOR*` can be chosen from `,`, `+`, `-`,
-`*`, `/`, `|`, `&` or nothing for no separator.
+`*`, `/`, `|`, `&` or nothing for no separator. The `*` separator needs to be written directly in
+front of the closing `*`, like `#( Tuple::SIZE )**`, as `#( Tuple::SIZE )* * 2` is the repetition
+followed by a multiplication.
+
+The associated type given to `for_tuples!( type Name = ..; )` can be any type that contains
+repetitions. Everything around the repetitions is emitted as written, e.g.
+`for_tuples!( type Array = [u8; #( 1 )+*]; )`. Repetitions can also be used to nest types, e.g.
+`for_tuples!( type Chained = #( Chain<Tuple, )* () #( > )*; )` expands to
+`Chain<T0, Chain<T1, ()>>`. Generic associated types are supported as well, e.g.
+`for_tuples!( type Item<'a> = ( #( Tuple::Item<'a> ),* ) where Self: 'a; )`. A where clause in
+front of `=`, like in `type Item<'a> where Self: 'a = ..;`, is moved behind the type, as this
+location is deprecated.
+
+The same applies to the expression of `for_tuples!( const NAME: Type = ..; )`, e.g.
+`for_tuples!( const MAX: usize = max_of(&[ #( Tuple::SIZE ),* ]); )` or
+`for_tuples!( const FLAGS: u32 = { let mut x = 0; #( x |= Tuple::FLAGS; )* x }; )`.
+
+To construct `Self`, `for_tuples!( Self( #( Tuple::new() )* ) )` can be used. It always expands
+to a well-formed tuple, e.g. `()` for zero elements and `(T0::new(),)` for one element. This
+requires `Self` to consist of the tuple elements, so for self types like `&Tuple` or
+`Wrapper<Tuple>` the tuple needs to be constructed with `for_tuples!( ( #( Tuple::new() ),* ) )`.
+
+The trait can also be implemented for references to tuple elements by using `&Tuple` or
+`&'a mut Tuple` as self type, e.g. `impl<'a> Trait for &'a mut Tuple` generates implementations
+for `(&'a mut T0, &'a mut T1, ..)`. Other types that wrap the placeholder, e.g. `Box<Tuple>`,
+`Wrapper<Tuple>` or `[Tuple; 2]`, are supported as well and the placeholder is replaced by the
+tuple type, e.g. `Wrapper<(T0, T1)>`. Primitive types like `u32` are never taken as placeholder,
+while other types in the self type need to be written as path with multiple segments, like
+`Wrapper<std::string::String, Tuple>`, to keep the placeholder unambiguous. As `self` wraps the
+tuple, the elements need to be accessed through the wrapped tuple, e.g. `self.0.Tuple` instead of
+`self.Tuple` or `Tuple.method()`.
+
+To wrap each tuple element, `for_tuples!` can be used as self type, e.g.
+`impl Trait for for_tuples!( ( #( Option<Tuple> ),* ) )` generates implementations for
+`(Option<T0>, Option<T1>, ..)`. `self.Tuple` still accesses the wrapped element. The repetition
+needs to be wrapped in parenthesis, as only this form is a tuple type.
+
+Custom where clauses can mix plain predicates and repetitions, e.g.
+`for_tuples!( where Self: Sized, #( Tuple: Foo<Tuple::Bar> )* );`. The impl block can contain
+multiple custom where clauses, all of them are merged into the where clause of the generated
+implementations.
+
+Methods support tuple repetitions in their signature. `for_tuples!( ( #( Tuple::Ret ),* ) )` can
+be used at any type position, e.g. the return type or the type of an argument. Generic
+parameters and where predicates are added to a method using the `#[for_tuples(..)]` attribute,
+e.g. `#[for_tuples(<#( Tuple_T: From<Tuple> ),*>)]` or `#[for_tuples(where #( Tuple: Trait )*)]`.
+
+An identifier that starts with the placeholder, followed by `_` or a digit (like `Tuple_T` or
+`Tuple2`), and that is declared inside a repetition, is replaced by a unique identifier per tuple
+element. Such an identifier is declared as generic parameter in `#[for_tuples(<..>)]` or bound by a
+pattern, e.g. `let Tuple_x = ..;`, and can be used in the following repetitions of the same
+function. All other identifiers are kept as they are. This can be used for local variables, e.g. to
+implement algorithms that work in two phases:
+
+```
+# use impl_trait_for_tuples::impl_for_tuples;
+trait Run {
+    fn start(&self) -> u32;
+    fn finish(&self, started: u32);
+}
+
+#[impl_for_tuples(1, 5)]
+impl Run for Tuple {
+    fn start(&self) -> u32 {
+        for_tuples!( #( Tuple.start() )+* )
+    }
+
+    fn finish(&self, _: u32) {
+        // `Tuple_started` is replaced by a distinct local variable per tuple element.
+        for_tuples!( #( let Tuple_started = Tuple.start(); )* );
+        for_tuples!( #( Tuple.finish(Tuple_started); )* );
+    }
+}
+
+# fn main() {}
+```
 
 By adding the `#[tuple_types_no_default_trait_bound]` above the impl block, the macro will not add the
 automatic bound to the implemented trait for each tuple type.
@@ -86,11 +200,135 @@ automatic bound to the implemented trait for each tuple type.
 The trait bound can be customized using `#[tuple_types_custom_trait_bound(NewBound)]`.
 The new bound will be used instead of the impleted trait for each tuple type.
 
+The bound of specific tuple types can be overridden by prefixing the bound with the index of the
+tuple type or `last` for the last tuple type, e.g.
+`#[tuple_types_custom_trait_bound(Trait, 0: Trait + Default, last: ?Sized + Trait)]`. This way
+tuples with an unsized last element, like `(A, [u8])`, are supported. Without a bound in front of
+the overrides, the implemented trait is used for the other tuple types. Overrides that match the
+same tuple type, like `0` and `last` for a tuple with one element, and indices that are out of range
+for all tuples are an error.
+
+When only overrides are given, `#[tuple_types_no_default_trait_bound]` can be added to not bound
+the other tuple types at all, e.g. `#[tuple_types_custom_trait_bound(last: ?Sized)]`.
+
+Additional bounds can be added on top of the implemented trait or the custom trait bound by using
+`#[tuple_types_extra_trait_bound(Send + 'static)]`. This attribute can be given multiple times.
+Combining `#[tuple_types_custom_trait_bound]` that has a bound in front of the overrides with
+`#[tuple_types_no_default_trait_bound]` or giving one of them multiple times is an error.
+
+The semi-automatic mode also supports inherent implementations for local types that wrap the tuple
+placeholder, e.g. `impl Wrapper<Tuple> { .. }`. As there is no implemented trait, no default bound
+is added to the tuple types. Bounds can be added with `#[tuple_types_custom_trait_bound(..)]` or
+`#[tuple_types_extra_trait_bound(..)]`.
+
+To keep related implementations in lockstep, the attribute can also be placed on an inline
+module, e.g. `#[impl_for_tuples(5)] mod tuple_impls { impl Encode for Tuple { .. } impl Decode for
+Tuple { .. } }`. Each implementation for the placeholder is expanded like it had the attribute on
+its own, all other items are kept as they are. The placeholder is taken from the first
+implementation whose self type is not declared or imported in the module. So, implementations for
+helper types of the module, like `impl Helper { .. }`, are not expanded.
+
+By passing `also_arrays` as last argument, e.g. `#[impl_for_tuples(1, 5, also_arrays)]`, the
+implementation is also generated for arrays with the same number of elements, e.g. `[T; 3]`. The
+same template is used, while `self.Tuple` is replaced by `self[0]`, `self[1]`, etc.,
+`for_tuples!( Self( .. ) )` constructs an array and `for_tuples!( ( #( Tuple ),* ) )` at a pattern
+position destructures an array. All elements of an array have the same type, so
+the placeholder needs to be the self type and per-index trait bounds are not supported.
+
+## Items per arity
+
+The `tuple_items!` macro expands arbitrary items, like functions, type aliases or multiple
+implementations, once for each tuple arity in the given range. The items use the same repetition
+syntax as the semi-automatic mode. Outside of repetitions, the placeholder is replaced by the tuple
+type and `#arity` by the number of tuple elements. To name items per arity, `#ident( .. )` joins
+the idents and integers in the parenthesis to one ident, e.g. `#ident(boxed_ #arity)` becomes
+`boxed_2`. Other idents that contain the placeholder, like `MyTuple`, are kept as they are.
+
+```
+# use impl_trait_for_tuples::tuple_items;
+tuple_items!(1..=5, |Tuple| {
+    fn #ident(boxed_ #arity)<#( Tuple ),*>(tuple: Tuple) -> ( #( Box<Tuple>, )* ) {
+        ( #( Box::new(tuple.Tuple), )* )
+    }
+});
+
+# fn main() {}
+```
+
+## Field-wise implementations for structs
+
+The `#[derive_fieldwise(..)]` attribute implements the given template implementations for a struct,
+while the fields of the struct are treated like the elements of a tuple. The template uses the same
+syntax as the semi-automatic mode, the placeholder is given as self type. `self.Fields` accesses the
+field and `for_tuples!( Self( #( Fields::new() )* ) )` constructs the struct field by field. By
+default, each field type is required to implement the trait. Generic structs are not supported.
+
+```
+# use impl_trait_for_tuples::derive_fieldwise;
+trait Clear {
+    fn clear(&mut self);
+}
+
+impl Clear for u32 {
+    fn clear(&mut self) {
+        *self = 0;
+    }
+}
+
+#[derive_fieldwise(
+    impl Clear for Fields {
+        fn clear(&mut self) {
+            for_tuples!( #( self.Fields.clear(); )* );
+        }
+    }
+)]
+struct Counters {
+    sent: u32,
+    received: u32,
+}
+
+# fn main() {}
+```
+
+## Visitors
+
+The `#[tuple_visit(..)]` attribute is placed on a trait and generates a visitor trait and a trait
+that calls the visitor for each element of a tuple. It accepts the same number of tuple elements as
+`#[impl_for_tuples(..)]`. For a trait `Plugin`, the visitor trait `PluginVisitor` requires
+`fn visit<T: Plugin>(&mut self, element: &T)`, while `visit_mut` and `visit_owned` call `visit` by
+default. The trait `PluginVisit` is implemented for all tuples whose elements implement `Plugin`
+and provides `visit_each`, `visit_each_mut` and `visit_each_owned`. Generic traits are not
+supported.
+
+## Companion crate
+
+The `impl-trait-for-tuples-traits` crate provides common tuple traits that are implemented by using
+this crate, like `TupleLen`, `TupleMap`, `TupleForEach`, `TuplePush`, `TupleConcat` and
+`TupleReverse`. It is `no_std` compatible and implements the traits for tuples with up to 12
+elements, the `arity-16` and `arity-32` features increase this number.
+
 ## Limitations
 
 The macro does not supports `for_tuples!` calls in a different macro, so stuff like
 `vec![ for_tuples!( bla ) ]` will generate invalid code.
 
+The standard library implements traits like `Debug`, `Default`, `PartialEq` or `Hash` only for
+tuples with up to 12 elements. If the implemented trait requires one of these traits as super
+trait, or a semi-automatic implementation requires it by `where Self: Trait`, while implementations
+for more than 12 elements are requested, a compile error is emitted. Only bare trait names and
+paths into `core`, `std` or `alloc` are checked, so an own trait with the same name can be used by
+its path, e.g. `crate::Hash`. Bounds given by `for_tuples!( where Self: .. )` are checked as well.
+
+To support more elements, `nested` can be given after the number of tuple elements, e.g.
+`#[impl_for_tuples(24, nested)]`. Then the trait is only implemented for tuples with up to 12
+elements and bigger tuples are represented as tuples of these, e.g. `((A, .., L), (M, .., X))`.
+They implement the trait through the implementations for the inner tuples, while the standard
+library implements its traits for all of them. So, `dyn_views` of such a tuple returns views of the
+inner tuples. This supports up to 144 elements and can not be
+combined with a minimum number of elements. The `nested!` macro groups the given types,
+expressions or patterns into this representation, e.g. `nested!(u32, u32, ..)` in type position
+or `nested!(1, 2, ..)` in expression position. Up to 12 elements, it returns a flat tuple.
+
 ## Example
 
 ### Full-automatic
@@ -128,6 +366,7 @@ impl Notify for TupleIdentifier {
 Licensed under either of
  * [Apache License, Version 2.0](http://www.apache.org/licenses/LICENSE-2.0)
  * [MIT license](http://opensource.org/licenses/MIT)
+
 at your option.
 */
 
@@ -139,12 +378,17 @@ use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
-    token, Attribute, Error, Ident, ItemImpl, ItemTrait, LitInt, Result,
+    token, Attribute, Error, Ident, ItemImpl, ItemMod, ItemStruct, ItemTrait, LitInt, Result,
+    Visibility,
 };
 
+mod fieldwise;
 mod full_automatic;
+mod nested;
 mod semi_automatic;
+mod tuple_items;
 mod utils;
+mod visit;
 
 /// Enum to parse the input and to distinguish between full/semi-automatic mode.
 enum FullOrSemiAutomatic {
@@ -152,6 +396,8 @@ enum FullOrSemiAutomatic {
     Full(ItemTrait),
     /// Sem-automatic trait implementation for tuples uses a trait implementation.
     Semi(ItemImpl),
+    /// Semi-automatic trait implementations for all trait implementations in a module.
+    Module(ItemMod),
 }
 
 impl Parse for FullOrSemiAutomatic {
@@ -159,6 +405,7 @@ impl Parse for FullOrSemiAutomatic {
         // We need to parse any attributes first, before we know what we actually can parse.
         let fork = input.fork();
         fork.call(Attribute::parse_outer)?;
+        fork.parse::<Visibility>()?;
 
         // If there is a `unsafe` in front of, just skip it.
         if fork.peek(token::Unsafe) {
@@ -169,55 +416,192 @@ impl Parse for FullOrSemiAutomatic {
 
         if lookahead1.peek(token::Impl) {
             Ok(Self::Semi(input.parse()?))
-        } else if lookahead1.peek(token::Trait) || lookahead1.peek(token::Pub) {
+        } else if lookahead1.peek(token::Trait) {
             Ok(Self::Full(input.parse()?))
+        } else if lookahead1.peek(token::Mod) {
+            Ok(Self::Module(input.parse()?))
         } else {
             Err(lookahead1.error())
         }
     }
 }
 
+mod keyword {
+    syn::custom_keyword!(also_arrays);
+    syn::custom_keyword!(dyn_views);
+    syn::custom_keyword!(enum_dispatch);
+    syn::custom_keyword!(nested);
+    syn::custom_keyword!(recursive);
+}
+
 /// The minimum and maximum given as two `LitInt`'s to the macro as arguments.
+///
+/// Optionally followed by the options `also_arrays`, `dyn_views`, `enum_dispatch` and `nested`.
+/// Alternatively, just `recursive` is given.
 struct MinMax {
     min: Option<usize>,
     max: usize,
+    /// Also generate the implementations for arrays.
+    also_arrays: Option<keyword::also_arrays>,
+    /// Also generate the `dyn Trait` views of the tuples.
+    dyn_views: Option<keyword::dyn_views>,
+    /// Also generate the enums that dispatch to one of their variants.
+    enum_dispatch: Option<keyword::enum_dispatch>,
+    /// Support more elements than the standard library by nesting tuples with up to
+    /// [`utils::STD_TUPLE_MAX_ELEMENTS`] elements.
+    nested: Option<keyword::nested>,
+    /// Generate the implementations for `()` and `(Head, Tail)`.
+    ///
+    /// Only nested tuples like `(A, (B, ()))` are supported, flat tuples with more than two
+    /// elements are not implemented.
+    recursive: Option<keyword::recursive>,
+}
+
+impl MinMax {
+    /// Returns the number of tuple elements for that an implementation should be generated.
+    fn arities(&self) -> Vec<usize> {
+        if self.recursive.is_some() {
+            vec![0, 2]
+        } else if self.nested.is_some() {
+            // Bigger tuples are nested tuples of these.
+            (0..=self.max.min(utils::STD_TUPLE_MAX_ELEMENTS)).collect()
+        } else {
+            (self.min.unwrap_or(0)..=self.max).collect()
+        }
+    }
+
+    /// Checks that `nested` can support `max` elements.
+    fn check_nested(&self) -> Result<()> {
+        let Some(nested) = self.nested else {
+            return Ok(());
+        };
+
+        // Up to 12 tuples with 12 elements each are supported by the standard library.
+        let max = utils::STD_TUPLE_MAX_ELEMENTS * utils::STD_TUPLE_MAX_ELEMENTS;
+        if self.min.is_some() {
+            Err(Error::new(
+                nested.span,
+                "`nested` requires the implementations for all tuples with up to 12 elements, \
+                 `min` is not supported!",
+            ))
+        } else if self.max > max {
+            Err(Error::new(
+                nested.span,
+                format!("`nested` supports up to {} elements!", max),
+            ))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl Parse for MinMax {
     fn parse(input: ParseStream) -> Result<Self> {
-        let args = Punctuated::<LitInt, token::Comma>::parse_terminated(input)?;
+        let mut args = Punctuated::<LitInt, token::Comma>::new();
+        let mut also_arrays = None;
+        let mut dyn_views = None;
+        let mut enum_dispatch = None;
+        let mut nested = None;
+
+        while !input.is_empty() {
+            if input.peek(keyword::recursive) {
+                let recursive = input.parse::<keyword::recursive>()?;
+
+                if !args.is_empty() || !input.is_empty() {
+                    return Err(Error::new(
+                        recursive.span,
+                        "`recursive` is expected to be the only argument!",
+                    ));
+                }
+
+                return Ok(Self {
+                    min: None,
+                    max: 2,
+                    also_arrays: None,
+                    dyn_views: None,
+                    enum_dispatch: None,
+                    nested: None,
+                    recursive: Some(recursive),
+                });
+            } else if input.peek(keyword::also_arrays)
+                || input.peek(keyword::dyn_views)
+                || input.peek(keyword::enum_dispatch)
+                || input.peek(keyword::nested)
+            {
+                // The options are given after the number of tuple elements.
+                while !input.is_empty() {
+                    let lookahead = input.lookahead1();
+
+                    if lookahead.peek(keyword::also_arrays) && also_arrays.is_none() {
+                        also_arrays = Some(input.parse()?);
+                    } else if lookahead.peek(keyword::dyn_views) && dyn_views.is_none() {
+                        dyn_views = Some(input.parse()?);
+                    } else if lookahead.peek(keyword::enum_dispatch) && enum_dispatch.is_none() {
+                        enum_dispatch = Some(input.parse()?);
+                    } else if lookahead.peek(keyword::nested) && nested.is_none() {
+                        nested = Some(input.parse()?);
+                    } else {
+                        return Err(lookahead.error());
+                    }
+
+                    if !input.is_empty() {
+                        input.parse::<token::Comma>()?;
+                    }
+                }
+            } else {
+                args.push_value(input.parse()?);
 
-        if args.is_empty() {
-            Err(Error::new(
+                if input.is_empty() {
+                    break;
+                }
+                args.push_punct(input.parse()?);
+            }
+        }
+
+        let min_max = if args.is_empty() {
+            return Err(Error::new(
                 Span::call_site(),
                 "Expected at least one argument to the macro!",
-            ))
+            ));
         } else if args.len() == 1 {
-            Ok(Self {
+            Self {
                 max: args[0].base10_parse()?,
                 min: None,
-            })
+                also_arrays,
+                dyn_views,
+                enum_dispatch,
+                nested,
+                recursive: None,
+            }
         } else if args.len() == 2 {
             let min = args[0].base10_parse()?;
             let max = args[1].base10_parse()?;
 
             if min >= max {
-                Err(Error::new(
+                return Err(Error::new(
                     Span::call_site(),
                     "It is expected that `min` comes before `max` and that `max > min` is true!",
-                ))
-            } else {
-                Ok(Self {
-                    min: Some(min),
-                    max,
-                })
+                ));
+            }
+
+            Self {
+                min: Some(min),
+                max,
+                also_arrays,
+                dyn_views,
+                enum_dispatch,
+                nested,
+                recursive: None,
             }
         } else {
-            Err(Error::new(
+            return Err(Error::new(
                 Span::call_site(),
                 "Too many arguments given to the macro!",
-            ))
-        }
+            ));
+        };
+
+        min_max.check_nested()?;
+        Ok(min_max)
     }
 }
 
@@ -235,21 +619,159 @@ pub fn impl_for_tuples(
         .into()
 }
 
+/// Generates visitor traits for the annotated trait and implements them for tuples.
+///
+/// See [crate](index.html) documentation.
+#[proc_macro_attribute]
+pub fn tuple_visit(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let definition = parse_macro_input!(input as ItemTrait);
+    let min_max = parse_macro_input!(args as MinMax);
+
+    tuple_visit_impl(definition, min_max)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Implements the given template implementations for a struct, field by field.
+///
+/// See [crate](index.html) documentation.
+#[proc_macro_attribute]
+pub fn derive_fieldwise(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let templates = parse_macro_input!(args as fieldwise::Templates);
+    let item = parse_macro_input!(input as ItemStruct);
+
+    fieldwise::fieldwise_impl(templates, item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Expands the given items once for each tuple arity in the given range.
+///
+/// See [crate](index.html) documentation.
+#[proc_macro]
+pub fn tuple_items(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as tuple_items::TupleItems);
+
+    tuple_items::tuple_items_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Groups the given elements into a tuple of tuples with up to 12 elements each.
+///
+/// See [crate](index.html) documentation.
+#[proc_macro]
+pub fn nested(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as nested::Nested);
+
+    nested::nested_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 fn impl_for_tuples_impl(input: FullOrSemiAutomatic, min_max: MinMax) -> Result<TokenStream> {
     let tuple_elements = (0usize..min_max.max)
-        .map(|i| generate_tuple_element_ident(i))
+        .map(generate_tuple_element_ident)
         .collect::<Vec<_>>();
 
+    let also_arrays = min_max.also_arrays.is_some();
+    let arities = min_max.arities();
+
     match input {
+        FullOrSemiAutomatic::Full(_) if also_arrays => Err(Error::new(
+            min_max.also_arrays.map_or_else(Span::call_site, |k| k.span),
+            "`also_arrays` is only supported in the semi-automatic mode!",
+        )),
         FullOrSemiAutomatic::Full(definition) => {
-            full_automatic::full_automatic_impl(definition, tuple_elements, min_max.min)
+            let views = match min_max.dyn_views {
+                Some(_) => full_automatic::dyn_views_impl(&definition, &tuple_elements, &arities)?,
+                None => TokenStream::new(),
+            };
+            let enums = match min_max.enum_dispatch {
+                Some(_) => {
+                    full_automatic::enum_dispatch_impl(&definition, &tuple_elements, &arities)?
+                }
+                None => TokenStream::new(),
+            };
+
+            let mut res =
+                full_automatic::full_automatic_impl(definition, tuple_elements, &arities)?;
+            res.extend(views);
+            res.extend(enums);
+            Ok(res)
         }
+        _ if min_max.dyn_views.is_some() => Err(Error::new(
+            min_max.dyn_views.map_or_else(Span::call_site, |k| k.span),
+            "`dyn_views` is only supported in the full-automatic mode!",
+        )),
+        _ if min_max.enum_dispatch.is_some() => Err(Error::new(
+            min_max
+                .enum_dispatch
+                .map_or_else(Span::call_site, |k| k.span),
+            "`enum_dispatch` is only supported in the full-automatic mode!",
+        )),
         FullOrSemiAutomatic::Semi(trait_impl) => {
-            semi_automatic::semi_automatic_impl(trait_impl, tuple_elements, min_max.min)
+            semi_automatic::semi_automatic_impl(trait_impl, tuple_elements, &arities, also_arrays)
         }
+        FullOrSemiAutomatic::Module(module) => semi_automatic::semi_automatic_module_impl(
+            module,
+            tuple_elements,
+            &arities,
+            also_arrays,
+        ),
     }
 }
 
+fn tuple_visit_impl(definition: ItemTrait, min_max: MinMax) -> Result<TokenStream> {
+    if let Some(recursive) = min_max.recursive {
+        return Err(Error::new(
+            recursive.span,
+            "`recursive` is not supported by `tuple_visit`!",
+        ));
+    }
+
+    if let Some(dyn_views) = min_max.dyn_views {
+        return Err(Error::new(
+            dyn_views.span,
+            "`dyn_views` is not supported by `tuple_visit`!",
+        ));
+    }
+
+    if let Some(enum_dispatch) = min_max.enum_dispatch {
+        return Err(Error::new(
+            enum_dispatch.span,
+            "`enum_dispatch` is not supported by `tuple_visit`!",
+        ));
+    }
+
+    if let Some(nested) = min_max.nested {
+        return Err(Error::new(
+            nested.span,
+            "`nested` is not supported by `tuple_visit`!",
+        ));
+    }
+
+    // The elements of an array can not be moved out by index, as required by `visit_each_owned`.
+    if let Some(also_arrays) = min_max.also_arrays {
+        return Err(Error::new(
+            also_arrays.span,
+            "`also_arrays` is not supported by `tuple_visit`!",
+        ));
+    }
+
+    let tuple_elements = (0usize..min_max.max)
+        .map(generate_tuple_element_ident)
+        .collect::<Vec<_>>();
+
+    visit::tuple_visit_impl(definition, tuple_elements, &min_max.arities())
+}
+
 fn generate_tuple_element_ident(num: usize) -> Ident {
     Ident::new(&format!("TupleElement{}", num), Span::call_site())
 }
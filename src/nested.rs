@@ -0,0 +1,85 @@
+//! Implementation of the `nested!` macro.
+//!
+//! The macro groups the given elements into a tuple of tuples with up to
+//! [`STD_TUPLE_MAX_ELEMENTS`] elements each, the shape supported by the `nested` option.
+
+use crate::utils::STD_TUPLE_MAX_ELEMENTS;
+
+use proc_macro2::{Span, TokenStream};
+
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    token, Error, Expr, Pat, Result, Type,
+};
+
+use quote::{quote, ToTokens};
+
+/// The input of the `nested!` macro.
+///
+/// The elements are types, expressions or patterns separated by `,`.
+pub struct Nested {
+    /// The elements of the tuple.
+    elements: Vec<TokenStream>,
+}
+
+impl Nested {
+    /// Parse the elements with the given `parser`, the separating `,` are dropped.
+    fn parse_elements<T: ToTokens>(
+        input: TokenStream,
+        parser: fn(ParseStream) -> Result<T>,
+    ) -> Result<Vec<TokenStream>> {
+        let elements = (|input: ParseStream| {
+            Punctuated::<T, token::Comma>::parse_terminated_with(input, parser)
+        })
+        .parse2(input)?;
+        Ok(elements.iter().map(ToTokens::to_token_stream).collect())
+    }
+}
+
+impl Parse for Nested {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let tokens = input.parse::<TokenStream>()?;
+
+        // The elements are only parsed to find the `,` between them, so the first kind that
+        // matches all elements is taken.
+        let elements = Self::parse_elements(tokens.clone(), Type::parse)
+            .or_else(|_| Self::parse_elements(tokens.clone(), Expr::parse))
+            .or_else(|_| Self::parse_elements(tokens, Pat::parse_multi))
+            .map_err(|e| {
+                Error::new(
+                    e.span(),
+                    "Expected types, expressions or patterns separated by `,`.",
+                )
+            })?;
+
+        Ok(Self { elements })
+    }
+}
+
+/// Generate the nested tuple for the elements given in `input`.
+pub fn nested_impl(input: Nested) -> Result<TokenStream> {
+    let elements = input.elements;
+
+    if elements.len() <= STD_TUPLE_MAX_ELEMENTS {
+        return Ok(quote!( ( #( #elements, )* ) ));
+    }
+
+    let chunks = elements
+        .chunks(STD_TUPLE_MAX_ELEMENTS)
+        .map(|chunk| quote!( ( #( #chunk, )* ) ))
+        .collect::<Vec<_>>();
+
+    if chunks.len() > STD_TUPLE_MAX_ELEMENTS {
+        return Err(Error::new(
+            Span::call_site(),
+            format!(
+                "`nested!` supports up to {} elements, found {}.",
+                STD_TUPLE_MAX_ELEMENTS * STD_TUPLE_MAX_ELEMENTS,
+                elements.len(),
+            ),
+        ));
+    }
+
+    Ok(quote!( ( #( #chunks, )* ) ))
+}
@@ -4,17 +4,20 @@
 //! tuple implementations. The user is able to use a special syntax `for_tuples!( #(TUPLE)* );` to
 //! express the tuple access while the `TUPLE` ident can be chosen by the user.
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use syn::{
-    bracketed,
     fold::{self, Fold},
     parenthesized,
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser},
     parse_quote,
+    punctuated::Punctuated,
     spanned::Spanned,
-    token, Block, Error, Expr, ExprField, FnArg, Ident, ImplItem, ImplItemFn, Index, ItemImpl,
-    Macro, Member, Meta, Result, Stmt, Type, WhereClause, WherePredicate,
+    token,
+    visit::{self, Visit},
+    Attribute, Block, Error, Expr, ExprField, FnArg, GenericParam, Generics, Ident, ImplItem,
+    ImplItemFn, Item, ItemImpl, ItemMod, LitInt, Macro, Member, Meta, Pat, PatIdent, Path, Result,
+    Stmt, Type, TypeParamBound, TypePath, TypeReference, UseTree, WhereClause, WherePredicate,
 };
 
 use quote::{quote, ToTokens};
@@ -23,6 +26,12 @@ use quote::{quote, ToTokens};
 /// attribute is given we don't add this bound.
 const TUPLE_TYPES_NO_DEFAULT_TRAIT_BOUND: &str = "tuple_types_no_default_trait_bound";
 const TUPLE_TYPES_CUSTOM_TRAIT_BOUND: &str = "tuple_types_custom_trait_bound";
+/// Attribute to add bounds to each tuple type, on top of the default or custom trait bound.
+const TUPLE_TYPES_EXTRA_TRAIT_BOUND: &str = "tuple_types_extra_trait_bound";
+/// Attribute to add tuple repetitions to the generics or the where clause of a method.
+const FOR_TUPLES_ATTRIBUTE: &str = "for_tuples";
+/// The ident `#index` is replaced with while parsing a repetition.
+const INDEX_PLACEHOLDER: &str = "__impl_trait_for_tuples_index";
 
 /// The supported separators in the `#( Tuple::test() )SEPARATOR*` syntax.
 enum Separator {
@@ -37,8 +46,16 @@ enum Separator {
 
 impl Separator {
     /// Try to parse the separator before the `*` token.
+    ///
+    /// `*` is only a separator when it is directly followed by the closing `*`, aka `**`. So,
+    /// `#( .. )* *` is the repetition followed by a `*` operator, see [`is_alone_star`].
     fn parse_before_star(input: ParseStream) -> Result<Option<Self>> {
-        if input.peek2(token::Star) {
+        let alone_star = input
+            .cursor()
+            .punct()
+            .is_some_and(|(punct, _)| is_alone_star(&punct));
+
+        if input.peek2(token::Star) && !alone_star {
             Self::parse(input).map(Some)
         } else {
             Ok(None)
@@ -69,6 +86,13 @@ impl Separator {
     }
 }
 
+/// Is the given `punct` a `*` that is not directly followed by another punctuation?
+///
+/// Used to distinguish the `*` separator in `#( .. )**` from `#( .. )* * value`.
+fn is_alone_star(punct: &Punct) -> bool {
+    punct.as_char() == '*' && punct.spacing() == Spacing::Alone
+}
+
 impl Parse for Separator {
     fn parse(input: ParseStream) -> Result<Self> {
         let lookahead1 = input.lookahead1();
@@ -98,6 +122,8 @@ enum Repetition {
     Stmts(Vec<Stmt>),
     Type(Type),
     Where(WherePredicate),
+    GenericParam(GenericParam),
+    Pat(Pat),
 }
 
 /// The `#( Tuple::test() )SEPARATOR*` (tuple repetition) syntax.
@@ -110,50 +136,95 @@ struct TupleRepetition {
 }
 
 impl TupleRepetition {
-    /// Parse the inner representation as stmts.
-    fn parse_as_stmts(input: ParseStream) -> Result<Self> {
+    /// Parse the repetition, while the inner representation is parsed by `parse_repetition`.
+    fn parse_with(
+        input: ParseStream,
+        parse_repetition: impl FnOnce(ParseStream) -> Result<Repetition>,
+    ) -> Result<Self> {
         let content;
         Ok(Self {
             pound_token: input.parse()?,
             _paren_token: parenthesized!(content in input),
-            repetition: Repetition::Stmts(content.call(Block::parse_within)?),
+            repetition: parse_repetition.parse2(replace_index_placeholder(content.parse()?))?,
             separator: Separator::parse_before_star(input)?,
             _star_token: input.parse()?,
         })
     }
 
+    /// Parse the inner representation as stmts.
+    fn parse_as_stmts(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, |content| {
+            content.call(Block::parse_within).map(Repetition::Stmts)
+        })
+    }
+
     /// Parse the inner representation as a where predicate.
     fn parse_as_where_predicate(input: ParseStream) -> Result<Self> {
-        let content;
-        Ok(Self {
-            pound_token: input.parse()?,
-            _paren_token: parenthesized!(content in input),
-            repetition: Repetition::Where(content.parse()?),
-            separator: Separator::parse_before_star(input)?,
-            _star_token: input.parse()?,
-        })
+        Self::parse_with(input, |content| content.parse().map(Repetition::Where))
     }
 
     /// Parse the inner representation as a type.
     fn parse_as_type(input: ParseStream) -> Result<Self> {
-        let content;
-        Ok(Self {
-            pound_token: input.parse()?,
-            _paren_token: parenthesized!(content in input),
-            repetition: Repetition::Type(content.parse()?),
-            separator: Separator::parse_before_star(input)?,
-            _star_token: input.parse()?,
+        Self::parse_with(input, |content| content.parse().map(Repetition::Type))
+    }
+
+    /// Parse the inner representation as a pattern.
+    fn parse_as_pat(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, |content| {
+            content
+                .call(Pat::parse_multi_with_leading_vert)
+                .map(Repetition::Pat)
+        })
+    }
+
+    /// Parse the inner representation as a generic parameter.
+    fn parse_as_generic_param(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, |content| {
+            content.parse().map(Repetition::GenericParam)
         })
     }
 
     /// Expand this repetition to the actual stmts implementation.
     fn expand_as_stmts(
-        self,
+        mut self,
         tuple_placeholder_ident: &Ident,
         tuples: &[Ident],
-        use_self: bool,
+        access: &ElementAccess,
+        self_access: SelfAccess,
+        declared_idents: &mut Vec<Ident>,
     ) -> Result<TokenStream> {
         let mut generated = TokenStream::new();
+        let separator = self.separator.take();
+        let elements = self.expand_as_stmts_per_element(
+            tuple_placeholder_ident,
+            tuples,
+            access,
+            self_access,
+            declared_idents,
+        )?;
+
+        for (i, element) in elements.into_iter().enumerate() {
+            generated.extend(element);
+
+            if let Some(ref sep) = separator {
+                generated.extend(sep.to_token_stream(i + 1 == tuples.len()));
+            }
+        }
+
+        Ok(generated)
+    }
+
+    /// Expand this repetition to the actual stmts implementation of each tuple element.
+    ///
+    /// The separator is ignored.
+    fn expand_as_stmts_per_element(
+        self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        access: &ElementAccess,
+        self_access: SelfAccess,
+        declared_idents: &mut Vec<Ident>,
+    ) -> Result<Vec<TokenStream>> {
         let span = self.pound_token.span();
         let stmts = match self.repetition {
             Repetition::Stmts(stmts) => stmts,
@@ -163,25 +234,28 @@ impl TupleRepetition {
             )),
         };
 
-        for (i, tuple) in tuples.iter().enumerate() {
-            generated.extend(stmts.iter().cloned().map(|s| {
-                ReplaceTuplePlaceholder::replace_ident_in_stmt(
-                    tuple_placeholder_ident,
-                    tuple,
-                    use_self,
-                    i,
-                    s,
-                )
-                .map(|s| s.to_token_stream())
-                .unwrap_or_else(|e| e.to_compile_error())
-            }));
-
-            if let Some(ref sep) = self.separator {
-                generated.extend(sep.to_token_stream(i + 1 == tuples.len()));
-            }
-        }
-
-        Ok(generated)
+        tuples
+            .iter()
+            .enumerate()
+            .map(|(i, tuple)| {
+                stmts
+                    .iter()
+                    .cloned()
+                    .map(|s| {
+                        ReplaceTuplePlaceholder::replace_ident_in_stmt(
+                            tuple_placeholder_ident,
+                            tuple,
+                            self_access,
+                            i,
+                            access,
+                            declared_idents,
+                            s,
+                        )
+                        .map(|s| s.to_token_stream())
+                    })
+                    .collect()
+            })
+            .collect()
     }
 
     /// Expand this repetition to the actual type declaration.
@@ -189,6 +263,7 @@ impl TupleRepetition {
         self,
         tuple_placeholder_ident: &Ident,
         tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
     ) -> Result<TokenStream> {
         let mut generated = TokenStream::new();
         let span = self.pound_token.span();
@@ -205,10 +280,11 @@ impl TupleRepetition {
                 ReplaceTuplePlaceholder::replace_ident_in_type(
                     tuple_placeholder_ident,
                     tuple,
+                    i,
+                    declared_idents,
                     ty.clone(),
-                )
-                .map(|s| s.to_token_stream())
-                .unwrap_or_else(|e| e.to_compile_error()),
+                )?
+                .to_token_stream(),
             );
 
             if let Some(ref sep) = self.separator {
@@ -219,12 +295,70 @@ impl TupleRepetition {
         Ok(generated)
     }
 
+    /// Expand this repetition to the actual patterns.
+    fn expand_as_pats(
+        mut self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
+    ) -> Result<TokenStream> {
+        let mut generated = TokenStream::new();
+        let separator = self.separator.take();
+        let elements =
+            self.expand_as_pats_per_element(tuple_placeholder_ident, tuples, declared_idents)?;
+
+        for (i, element) in elements.into_iter().enumerate() {
+            generated.extend(element);
+
+            if let Some(ref sep) = separator {
+                generated.extend(sep.to_token_stream(i + 1 == tuples.len()));
+            }
+        }
+
+        Ok(generated)
+    }
+
+    /// Expand this repetition to the actual pattern of each tuple element.
+    ///
+    /// The separator is ignored.
+    fn expand_as_pats_per_element(
+        self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
+    ) -> Result<Vec<TokenStream>> {
+        let span = self.pound_token.span();
+        let pat = match self.repetition {
+            Repetition::Pat(pat) => pat,
+            _ => return Err(Error::new(
+                span,
+                "Internal error, expected `repetition` to be of type `Pat`! Please report this issue!",
+            )),
+        };
+
+        tuples
+            .iter()
+            .enumerate()
+            .map(|(i, tuple)| {
+                ReplaceTuplePlaceholder::replace_ident_in_pat(
+                    tuple_placeholder_ident,
+                    tuple,
+                    i,
+                    declared_idents,
+                    pat.clone(),
+                )
+                .map(|s| s.to_token_stream())
+            })
+            .collect()
+    }
+
     /// Expand this to the given `where_clause`.
     /// It is expected that the instance was created with `parse_as_where_predicate`.
     fn expand_to_where_clause(
         self,
         tuple_placeholder_ident: &Ident,
         tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
         where_clause: &mut WhereClause,
     ) -> Result<()> {
         let span = self.pound_token.span();
@@ -236,11 +370,13 @@ impl TupleRepetition {
             )),
         };
 
-        for tuple in tuples.iter() {
+        for (i, tuple) in tuples.iter().enumerate() {
             where_clause.predicates.push(
                 ReplaceTuplePlaceholder::replace_ident_in_where_predicate(
                     tuple_placeholder_ident,
                     tuple,
+                    i,
+                    declared_idents,
                     predicate.clone(),
                 )?,
             );
@@ -248,38 +384,309 @@ impl TupleRepetition {
 
         Ok(())
     }
+
+    /// Expand this repetition to the generic parameters it represents.
+    /// It is expected that the instance was created with `parse_as_generic_param`.
+    fn expand_as_generic_params(
+        self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
+    ) -> Result<Vec<GenericParam>> {
+        let span = self.pound_token.span();
+        let param = match self.repetition {
+            Repetition::GenericParam(param) => param,
+            _ => return Err(Error::new(
+                span,
+                "Internal error, expected `repetition` to be of type `GenericParam`! Please report this issue!",
+            )),
+        };
+
+        tuples
+            .iter()
+            .enumerate()
+            .map(|(i, tuple)| {
+                ReplaceTuplePlaceholder::replace_ident_in_generic_param(
+                    tuple_placeholder_ident,
+                    tuple,
+                    i,
+                    declared_idents,
+                    param.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Replace all `#index` in the given `tokens` with [`INDEX_PLACEHOLDER`].
+///
+/// `#index` is not valid Rust syntax, so we need to replace it before parsing the repetition.
+fn replace_index_placeholder(tokens: TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut res = TokenStream::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref pound) if pound.as_char() == '#' => match tokens.peek() {
+                Some(TokenTree::Ident(ident)) if ident == "index" => {
+                    res.extend(Some(TokenTree::Ident(Ident::new(
+                        INDEX_PLACEHOLDER,
+                        ident.span(),
+                    ))));
+                    tokens.next();
+                }
+                _ => res.extend(Some(token)),
+            },
+            TokenTree::Group(group) => res.extend(Some(TokenTree::Group(with_stream(
+                &group,
+                replace_index_placeholder(group.stream()),
+            )))),
+            _ => res.extend(Some(token)),
+        }
+    }
+
+    res
+}
+
+/// Returns a copy of `group` with the given `stream`.
+pub(crate) fn with_stream(group: &Group, stream: TokenStream) -> Group {
+    let mut res = Group::new(group.delimiter(), stream);
+    res.set_span(group.span());
+    res
+}
+
+/// Expand all tuple repetitions found in the given `tokens`, while all the tokens around the
+/// repetitions are kept as they are.
+///
+/// In contrast to the other expansions, this works directly on the tokens. So, a repetition can
+/// be found at any position, e.g. `[u8; #( Tuple::LEN )+*]` or `#( Chain<Tuple, )* () #( > )*`.
+pub(crate) fn expand_repetitions_in_tokens(
+    tokens: TokenStream,
+    tuple_placeholder_ident: &Ident,
+    tuples: &[Ident],
+    access: &ElementAccess,
+) -> Result<TokenStream> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut res = TokenStream::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match (&tokens[i], tokens.get(i + 1)) {
+            (TokenTree::Punct(pound), Some(TokenTree::Group(group)))
+                if pound.as_char() == '#' && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let is_star = |t: Option<&TokenTree>| matches!(t, Some(TokenTree::Punct(star)) if star.as_char() == '*');
+                let is_alone_star =
+                    matches!(tokens.get(i + 2), Some(TokenTree::Punct(p)) if is_alone_star(p));
+
+                let separator = if is_star(tokens.get(i + 3)) && !is_alone_star {
+                    i += 4;
+                    Some(syn::parse2::<Separator>(tokens[i - 2].clone().into())?)
+                } else if is_star(tokens.get(i + 2)) {
+                    i += 3;
+                    None
+                } else {
+                    return Err(Error::new(
+                        pound.span(),
+                        "Expected `*` or a separator followed by `*` after the tuple repetition.",
+                    ));
+                };
+
+                for (index, tuple) in tuples.iter().enumerate() {
+                    res.extend(replace_placeholder_in_tokens(
+                        group.stream(),
+                        tuple_placeholder_ident,
+                        tuple,
+                        index,
+                        access,
+                    ));
+
+                    if let Some(ref sep) = separator {
+                        res.extend(sep.to_token_stream(index + 1 == tuples.len()));
+                    }
+                }
+            }
+            (TokenTree::Group(group), _) => {
+                res.extend(Some(TokenTree::Group(with_stream(
+                    group,
+                    expand_repetitions_in_tokens(
+                        group.stream(),
+                        tuple_placeholder_ident,
+                        tuples,
+                        access,
+                    )?,
+                ))));
+                i += 1;
+            }
+            (token, _) => {
+                res.extend(Some(token.clone()));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Replace the tuple placeholder `search` in the given `tokens` with `replace`.
+///
+/// `index` is the index of the tuple element and used to replace `#index` and `expr.Tuple`, see
+/// [`ElementAccess`].
+fn replace_placeholder_in_tokens(
+    tokens: TokenStream,
+    search: &Ident,
+    replace: &Ident,
+    index: usize,
+    access: &ElementAccess,
+) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut res = TokenStream::new();
+    // Tracks if the last token was a `.`, to not confuse `..Tuple` with a field access.
+    let mut last_was_dot = false;
+
+    while let Some(token) = tokens.next() {
+        let is_dot = matches!(token, TokenTree::Punct(ref dot) if dot.as_char() == '.');
+        let is_field_access = is_dot
+            && !last_was_dot
+            && matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == search);
+        last_was_dot = is_dot;
+
+        match token {
+            TokenTree::Punct(ref dot) if is_field_access => {
+                res.extend(access.access_tokens(index, dot.span()));
+                tokens.next();
+            }
+            TokenTree::Ident(ref ident) if ident == search => {
+                res.extend(Some(TokenTree::Ident(replace.clone())))
+            }
+            TokenTree::Punct(ref pound) if pound.as_char() == '#' => match tokens.peek() {
+                Some(TokenTree::Ident(ident)) if ident == "index" => {
+                    res.extend(LitInt::new(&index.to_string(), ident.span()).to_token_stream());
+                    tokens.next();
+                }
+                _ => res.extend(Some(token)),
+            },
+            TokenTree::Group(group) => res.extend(Some(TokenTree::Group(with_stream(
+                &group,
+                replace_placeholder_in_tokens(group.stream(), search, replace, index, access),
+            )))),
+            _ => res.extend(Some(token)),
+        }
+    }
+
+    res
+}
+
+/// Split the given type `tokens` into the type and the where clause after it, if there is any.
+fn split_trailing_where_clause(tokens: TokenStream) -> Result<(TokenStream, Option<WhereClause>)> {
+    let mut tokens = tokens.into_iter().collect::<Vec<_>>();
+
+    match tokens
+        .iter()
+        .position(|t| matches!(t, TokenTree::Ident(ident) if ident == "where"))
+    {
+        Some(pos) => {
+            let where_clause = syn::parse2(tokens.split_off(pos).into_iter().collect())?;
+            Ok((tokens.into_iter().collect(), Some(where_clause)))
+        }
+        None => Ok((tokens.into_iter().collect(), None)),
+    }
+}
+
+/// Parse all tokens until the next `;`.
+fn parse_tokens_until_semi(input: ParseStream) -> Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+
+    while !input.is_empty() && !input.peek(token::Semi) {
+        tokens.extend(Some(input.parse::<TokenTree>()?));
+    }
+
+    Ok(tokens)
+}
+
+/// How the tuple elements are accessed, e.g. by `self.Tuple`.
+pub enum ElementAccess {
+    /// By the index of the tuple element, e.g. `self.0`.
+    Tuple,
+    /// By the given members, e.g. `self.name` for the fields of a struct.
+    Members(Vec<Member>),
+    /// By indexing an array, e.g. `self[0]`.
+    Array,
+}
+
+impl ElementAccess {
+    /// Returns the expression that accesses the element at `index` of `base`.
+    fn access(&self, base: Expr, index: usize, span: Span) -> Expr {
+        let access = self.access_tokens(index, span);
+        parse_quote!( #base #access )
+    }
+
+    /// Returns the tokens that access the element at `index`, when put after an expression.
+    fn access_tokens(&self, index: usize, span: Span) -> TokenStream {
+        let index_lit = LitInt::new(&index.to_string(), span);
+
+        match self {
+            Self::Tuple => quote!( . #index_lit ),
+            Self::Members(members) => {
+                let member = &members[index];
+                quote!( . #member )
+            }
+            Self::Array => quote!( [#index_lit] ),
+        }
+    }
+}
+
+/// How the tuple elements can be accessed through `self`.
+#[derive(Clone, Copy)]
+enum SelfAccess {
+    /// There is no `self`, e.g. in a static method.
+    None,
+    /// `self` consists of the tuple elements, e.g. `self.0`.
+    Tuple,
+    /// `self` wraps the tuple, e.g. `Wrapper<Tuple>`, so the elements can not be accessed directly.
+    Wrapped,
 }
 
 /// Replace the tuple place holder in the ast.
 struct ReplaceTuplePlaceholder<'a> {
     search: &'a Ident,
     replace: &'a Ident,
-    use_self: bool,
-    index: Index,
+    self_access: SelfAccess,
+    index: usize,
+    access: &'a ElementAccess,
+    /// The idents declared inside repetitions that are derived per tuple element, see
+    /// [`derive_ident_from_placeholder`].
+    declared_idents: &'a mut Vec<Ident>,
     errors: Vec<Error>,
 }
 
 impl<'a> ReplaceTuplePlaceholder<'a> {
-    /// Replace the given `replace` ident in the given `stmt`.
-    fn replace_ident_in_stmt(
+    /// Create a new instance that replaces the given `search` ident with `replace`.
+    fn new(
         search: &'a Ident,
         replace: &'a Ident,
-        use_self: bool,
+        self_access: SelfAccess,
         index: usize,
-        stmt: Stmt,
-    ) -> Result<Stmt> {
-        let mut folder = Self {
+        access: &'a ElementAccess,
+        declared_idents: &'a mut Vec<Ident>,
+    ) -> Self {
+        Self {
             search,
             replace,
-            use_self,
-            index: index.into(),
+            self_access,
+            index,
+            access,
+            declared_idents,
             errors: Vec::new(),
-        };
+        }
+    }
 
-        let res = fold::fold_stmt(&mut folder, stmt);
+    /// Replace the ident in `value`, by folding it with `fold`.
+    fn replace<T>(mut self, value: T, fold: fn(&mut Self, T) -> T) -> Result<T> {
+        let res = fold(&mut self, value);
 
-        if let Some(first) = folder.errors.pop() {
-            Err(folder.errors.into_iter().fold(first, |mut e, n| {
+        if let Some(first) = self.errors.pop() {
+            Err(self.errors.into_iter().fold(first, |mut e, n| {
                 e.combine(n);
                 e
             }))
@@ -288,51 +695,125 @@ impl<'a> ReplaceTuplePlaceholder<'a> {
         }
     }
 
+    /// Replace the given `replace` ident in the given `stmt`.
+    fn replace_ident_in_stmt(
+        search: &'a Ident,
+        replace: &'a Ident,
+        self_access: SelfAccess,
+        index: usize,
+        access: &'a ElementAccess,
+        declared_idents: &'a mut Vec<Ident>,
+        stmt: Stmt,
+    ) -> Result<Stmt> {
+        Self::new(search, replace, self_access, index, access, declared_idents)
+            .replace(stmt, fold::fold_stmt)
+    }
+
     /// Replace the given `replace` ident in the given `type_`.
-    fn replace_ident_in_type(search: &'a Ident, replace: &'a Ident, type_: Type) -> Result<Type> {
-        let mut folder = Self {
+    fn replace_ident_in_type(
+        search: &'a Ident,
+        replace: &'a Ident,
+        index: usize,
+        declared_idents: &'a mut Vec<Ident>,
+        type_: Type,
+    ) -> Result<Type> {
+        Self::new(
             search,
             replace,
-            use_self: false,
-            index: 0.into(),
-            errors: Vec::new(),
-        };
-
-        let res = fold::fold_type(&mut folder, type_);
+            SelfAccess::None,
+            index,
+            &ElementAccess::Tuple,
+            declared_idents,
+        )
+        .replace(type_, fold::fold_type)
+    }
 
-        if let Some(first) = folder.errors.pop() {
-            Err(folder.errors.into_iter().fold(first, |mut e, n| {
-                e.combine(n);
-                e
-            }))
-        } else {
-            Ok(res)
-        }
+    /// Replace the given `replace` ident in the given `pat`.
+    fn replace_ident_in_pat(
+        search: &'a Ident,
+        replace: &'a Ident,
+        index: usize,
+        declared_idents: &'a mut Vec<Ident>,
+        pat: Pat,
+    ) -> Result<Pat> {
+        Self::new(
+            search,
+            replace,
+            SelfAccess::None,
+            index,
+            &ElementAccess::Tuple,
+            declared_idents,
+        )
+        .replace(pat, fold::fold_pat)
     }
 
     /// Replace the given `replace` ident in the given `where_predicate`.
     fn replace_ident_in_where_predicate(
         search: &'a Ident,
         replace: &'a Ident,
+        index: usize,
+        declared_idents: &'a mut Vec<Ident>,
         where_predicate: WherePredicate,
     ) -> Result<WherePredicate> {
-        let mut folder = Self {
+        Self::new(
             search,
             replace,
-            use_self: false,
-            index: 0.into(),
-            errors: Vec::new(),
-        };
+            SelfAccess::None,
+            index,
+            &ElementAccess::Tuple,
+            declared_idents,
+        )
+        .replace(where_predicate, fold::fold_where_predicate)
+    }
 
-        let res = fold::fold_where_predicate(&mut folder, where_predicate);
+    /// Replace the given `replace` ident in the given `generic_param`.
+    fn replace_ident_in_generic_param(
+        search: &'a Ident,
+        replace: &'a Ident,
+        index: usize,
+        declared_idents: &'a mut Vec<Ident>,
+        generic_param: GenericParam,
+    ) -> Result<GenericParam> {
+        Self::new(
+            search,
+            replace,
+            SelfAccess::None,
+            index,
+            &ElementAccess::Tuple,
+            declared_idents,
+        )
+        .replace(generic_param, Self::fold_generic_param)
+    }
+}
 
-        if let Some(first) = folder.errors.pop() {
-            Err(folder.errors.into_iter().fold(first, |mut e, n| {
-                e.combine(n);
-                e
-            }))
-        } else {
-            Ok(res)
+impl<'a> ReplaceTuplePlaceholder<'a> {
+    /// Returns the ident of the value bound by the placeholder in a pattern, e.g. in
+    /// `let for_tuples!( ( #( Tuple ),* ) ) = self;`.
+    ///
+    /// It is distinct from the ident of the tuple element type.
+    fn value_ident(&self, span: Span) -> Ident {
+        Ident::new(&format!("tuple_element_{}", self.index), span)
+    }
+
+    /// Push the error for accessing the tuple elements through `self`, while the self type wraps
+    /// the tuple.
+    fn push_wrapped_self_error(&mut self, span: Span) {
+        self.errors.push(Error::new(
+            span,
+            format!(
+                "`self` wraps the tuple, so it does not give access to the tuple elements. \
+                 Access them through the wrapped tuple, e.g. `self.0.{}`.",
+                self.search,
+            ),
+        ));
+    }
+
+    /// Declare `ident` as derived per tuple element, if it uses the placeholder as prefix.
+    fn declare_ident(&mut self, ident: &Ident) {
+        if derive_ident_from_placeholder(self.search, self.index, ident).is_some()
+            && !self.declared_idents.contains(ident)
+        {
+            self.declared_idents.push(ident.clone());
         }
     }
 }
@@ -341,103 +822,179 @@ impl<'a> Fold for ReplaceTuplePlaceholder<'a> {
     fn fold_ident(&mut self, ident: Ident) -> Ident {
         if &ident == self.search {
             self.replace.clone()
+        } else if self.declared_idents.contains(&ident) {
+            derive_ident_from_placeholder(self.search, self.index, &ident).unwrap_or(ident)
         } else {
             ident
         }
     }
 
+    fn fold_pat_ident(&mut self, mut pat: PatIdent) -> PatIdent {
+        if &pat.ident == self.search {
+            // The placeholder binds the value of the element, so it gets a value ident.
+            pat.ident = self.value_ident(pat.ident.span());
+            pat.subpat = pat
+                .subpat
+                .map(|(at, subpat)| (at, Box::new(self.fold_pat(*subpat))));
+            return pat;
+        }
+
+        self.declare_ident(&pat.ident);
+        fold::fold_pat_ident(self, pat)
+    }
+
+    fn fold_generic_param(&mut self, param: GenericParam) -> GenericParam {
+        match &param {
+            GenericParam::Type(param) => self.declare_ident(&param.ident),
+            GenericParam::Const(param) => self.declare_ident(&param.ident),
+            GenericParam::Lifetime(param) => self.declare_ident(&param.lifetime.ident),
+        }
+
+        fold::fold_generic_param(self, param)
+    }
+
     fn fold_expr(&mut self, expr: Expr) -> Expr {
         match expr {
             Expr::MethodCall(mut call) => match *call.receiver {
-                Expr::Path(ref path) if path.path.is_ident(self.search) => {
-                    if self.use_self {
-                        let index = &self.index;
-                        call.receiver = parse_quote!( self.#index );
+                Expr::Path(ref path) if path.path.is_ident(self.search) => match self.self_access {
+                    SelfAccess::Tuple => {
+                        call.receiver = Box::new(self.access.access(
+                            parse_quote!(self),
+                            self.index,
+                            path.span(),
+                        ));
 
-                        call.into()
-                    } else {
+                        fold::fold_expr_method_call(self, call).into()
+                    }
+                    SelfAccess::None => {
                         self.errors.push(Error::new(
                             path.span(),
                             "Can not call non-static method from within a static method.",
                         ));
                         Expr::Verbatim(Default::default())
                     }
-                }
+                    SelfAccess::Wrapped => {
+                        self.push_wrapped_self_error(path.span());
+                        Expr::Verbatim(Default::default())
+                    }
+                },
                 _ => fold::fold_expr_method_call(self, call).into(),
             },
-            _ => fold::fold_expr(self, expr),
-        }
-    }
+            Expr::Path(ref path) if path.qself.is_none() && path.path.is_ident(self.search) => {
+                // The placeholder as value refers to the element bound by a pattern.
+                let ident = self.value_ident(path.span());
+                parse_quote!( #ident )
+            }
+            Expr::Path(ref path) if path.path.is_ident(INDEX_PLACEHOLDER) => {
+                // Replace `#index` with `0`, `1`, etc.
+                let index = LitInt::new(&self.index.to_string(), path.span());
+                parse_quote!( #index )
+            }
+            Expr::Field(ExprField {
+                base,
+                member: Member::Named(ref ident),
+                ..
+            }) if ident == self.search => {
+                if matches!(self.self_access, SelfAccess::Wrapped)
+                    && matches!(*base, Expr::Path(ref path) if path.path.is_ident("self"))
+                {
+                    self.push_wrapped_self_error(base.span());
+                    return Expr::Verbatim(Default::default());
+                }
 
-    fn fold_expr_field(&mut self, mut expr: ExprField) -> ExprField {
-        match expr.member {
-            Member::Named(ref ident) if ident == self.search => {
                 // Replace `something.Tuple` with `something.0`, `something.1`, etc.
-                expr.member = Member::Unnamed(self.index.clone());
-                expr
+                let base = self.fold_expr(*base);
+                self.access.access(base, self.index, ident.span())
             }
-            _ => expr,
+            _ => fold::fold_expr(self, expr),
         }
     }
 }
 
-/// The expression of a const item.
-enum ConstExpr {
-    /// repetition
-    Simple { tuple_repetition: TupleRepetition },
-    /// &[ repetition ]
-    RefArray {
-        and_token: token::And,
-        bracket_token: token::Bracket,
-        tuple_repetition: TupleRepetition,
-    },
+/// Derive a per tuple element ident from an ident that uses the tuple placeholder as prefix.
+///
+/// The placeholder needs to be followed by `_` or a digit, e.g. `Tuple2` or `Tuple_result`.
+/// The placeholder is replaced by the ident of the tuple element at `index` and the suffix is
+/// kept, while it is always separated by `_` to not clash with other tuple element idents. The
+/// ident only depends on `index`, as the elements of an array share the same type.
+///
+/// Only idents declared inside a repetition are derived, aka generic parameters and idents bound
+/// by a pattern, e.g. `let Tuple_result = ..;`. All other idents are kept as they are.
+fn derive_ident_from_placeholder(search: &Ident, index: usize, ident: &Ident) -> Option<Ident> {
+    let ident_str = ident.to_string();
+    let suffix = ident_str.strip_prefix(&search.to_string())?;
+    let element = crate::generate_tuple_element_ident(index);
+
+    let derived = if suffix.starts_with('_') {
+        format!("{}{}", element, suffix)
+    } else if suffix.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}_{}", element, suffix)
+    } else {
+        return None;
+    };
+
+    Some(Ident::new(&derived, ident.span()))
 }
 
-impl ConstExpr {
-    /// Expand `self` into a [`TokenStream`].
-    fn expand(
-        self,
-        tuple_placeholder_ident: &Ident,
-        tuples: &[Ident],
-        use_self: bool,
-    ) -> Result<TokenStream> {
-        match self {
-            Self::Simple { tuple_repetition } => {
-                tuple_repetition.expand_as_stmts(tuple_placeholder_ident, tuples, use_self)
-            }
-            Self::RefArray {
-                and_token,
-                bracket_token,
-                tuple_repetition,
-            } => {
-                let repetition =
-                    tuple_repetition.expand_as_stmts(tuple_placeholder_ident, tuples, use_self)?;
+/// An item of a custom where clause.
+enum WhereItem {
+    /// A plain where predicate, e.g. `Self: Sized`.
+    Predicate(WherePredicate),
+    /// A tuple repetition, e.g. `#( Tuple: Trait )*`.
+    Repetition(TupleRepetition),
+}
+
+impl WhereItem {
+    /// Parse all items of a custom where clause.
+    ///
+    /// Plain predicates need to be separated by `,`, while the `,` after a repetition is optional.
+    fn parse_list(input: ParseStream) -> Result<Vec<Self>> {
+        let mut items = Vec::new();
+
+        while !input.is_empty() {
+            if input.peek(token::Pound) {
+                items.push(Self::Repetition(
+                    input.call(TupleRepetition::parse_as_where_predicate)?,
+                ));
+
+                if input.peek(token::Comma) {
+                    input.parse::<token::Comma>()?;
+                }
+            } else {
+                items.push(Self::Predicate(input.parse()?));
 
-                let mut token_stream = and_token.to_token_stream();
-                bracket_token.surround(&mut token_stream, |tokens| tokens.extend(repetition));
-                Ok(token_stream)
+                if !input.is_empty() {
+                    input.parse::<token::Comma>()?;
+                }
             }
         }
-    }
-}
 
-impl Parse for ConstExpr {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let lookahead1 = input.lookahead1();
-
-        if lookahead1.peek(token::And) {
-            let content;
-            Ok(ConstExpr::RefArray {
-                and_token: input.parse()?,
-                bracket_token: bracketed!(content in input),
-                tuple_repetition: content.call(TupleRepetition::parse_as_stmts)?,
-            })
-        } else if lookahead1.peek(token::Pound) {
-            Ok(ConstExpr::Simple {
-                tuple_repetition: TupleRepetition::parse_as_stmts(input)?,
-            })
+        if items.is_empty() {
+            Err(input.error("Expected at least one where predicate!"))
         } else {
-            Err(lookahead1.error())
+            Ok(items)
+        }
+    }
+
+    /// Expand this item into the given `where_clause`.
+    fn expand_to_where_clause(
+        self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
+        where_clause: &mut WhereClause,
+    ) -> Result<()> {
+        match self {
+            Self::Predicate(predicate) => {
+                where_clause.predicates.push(predicate);
+                Ok(())
+            }
+            Self::Repetition(repetition) => repetition.expand_to_where_clause(
+                tuple_placeholder_ident,
+                tuples,
+                declared_idents,
+                where_clause,
+            ),
         }
     }
 }
@@ -448,9 +1005,11 @@ enum ForTuplesMacro {
     ItemType {
         type_token: token::Type,
         ident: Ident,
+        /// The generics and the where clause in front of `=` of a generic associated type.
+        generics: Generics,
         equal_token: token::Eq,
-        paren_token: token::Paren,
-        tuple_repetition: TupleRepetition,
+        /// The type that contains the tuple repetitions, including a trailing where clause.
+        ty: TokenStream,
         semi_token: token::Semi,
     },
     /// The macro at an item const position.
@@ -458,9 +1017,10 @@ enum ForTuplesMacro {
         const_token: token::Const,
         ident: Ident,
         colon_token: token::Colon,
-        const_type: Type,
+        const_type: Box<Type>,
         equal_token: token::Eq,
-        expr: ConstExpr,
+        /// The expression that contains the tuple repetitions.
+        expr: TokenStream,
         semi_token: token::Semi,
     },
     /// The repetition stmt wrapped in parenthesis.
@@ -470,10 +1030,32 @@ enum ForTuplesMacro {
     },
     /// Just the repetition stmt.
     Stmt { tuple_repetition: TupleRepetition },
+    /// The construction of `Self` from one expression per tuple element.
+    ///
+    /// Only supported if `Self` consists of the tuple elements, e.g. not for `Wrapper<Tuple>`.
+    SelfConstruction {
+        self_token: token::SelfType,
+        paren_token: token::Paren,
+        tuple_repetition: TupleRepetition,
+    },
+    /// The repetition type wrapped in parenthesis, at a type position.
+    TypeParenthesized {
+        paren_token: token::Paren,
+        tuple_repetition: TupleRepetition,
+    },
+    /// Just the repetition type, at a type position.
+    Type { tuple_repetition: TupleRepetition },
+    /// The repetition pattern wrapped in parenthesis, at a pattern position.
+    PatParenthesized {
+        paren_token: token::Paren,
+        tuple_repetition: TupleRepetition,
+    },
+    /// Just the repetition pattern, at a pattern position.
+    Pat { tuple_repetition: TupleRepetition },
     /// A custom where clause.
     Where {
         _where_token: token::Where,
-        tuple_repetition: TupleRepetition,
+        items: Vec<WhereItem>,
     },
 }
 
@@ -482,13 +1064,16 @@ impl Parse for ForTuplesMacro {
         let lookahead1 = input.lookahead1();
 
         if lookahead1.peek(token::Type) {
-            let content;
             Ok(ForTuplesMacro::ItemType {
                 type_token: input.parse()?,
                 ident: input.parse()?,
+                generics: {
+                    let mut generics = input.parse::<Generics>()?;
+                    generics.where_clause = input.parse()?;
+                    generics
+                },
                 equal_token: input.parse()?,
-                paren_token: parenthesized!(content in input),
-                tuple_repetition: content.call(TupleRepetition::parse_as_type)?,
+                ty: input.call(parse_tokens_until_semi)?,
                 semi_token: input.parse()?,
             })
         } else if lookahead1.peek(token::Const) {
@@ -498,7 +1083,7 @@ impl Parse for ForTuplesMacro {
                 colon_token: input.parse()?,
                 const_type: input.parse()?,
                 equal_token: input.parse()?,
-                expr: input.parse()?,
+                expr: input.call(parse_tokens_until_semi)?,
                 semi_token: input.parse()?,
             })
         } else if lookahead1.peek(token::Paren) {
@@ -507,6 +1092,23 @@ impl Parse for ForTuplesMacro {
                 paren_token: parenthesized!(content in input),
                 tuple_repetition: content.call(TupleRepetition::parse_as_stmts)?,
             })
+        } else if lookahead1.peek(token::SelfType) {
+            let content;
+            let self_token = input.parse()?;
+            let paren_token = parenthesized!(content in input);
+            let tuple_repetition = content.call(TupleRepetition::parse_as_stmts)?;
+
+            match tuple_repetition.separator {
+                None | Some(Separator::Comma(_)) => Ok(ForTuplesMacro::SelfConstruction {
+                    self_token,
+                    paren_token,
+                    tuple_repetition,
+                }),
+                Some(_) => Err(Error::new(
+                    tuple_repetition.pound_token.span(),
+                    "Only `,` is supported as separator when constructing `Self`.",
+                )),
+            }
         } else if lookahead1.peek(token::Pound) {
             Ok(ForTuplesMacro::Stmt {
                 tuple_repetition: input.call(TupleRepetition::parse_as_stmts)?,
@@ -514,7 +1116,7 @@ impl Parse for ForTuplesMacro {
         } else if lookahead1.peek(token::Where) {
             Ok(ForTuplesMacro::Where {
                 _where_token: input.parse()?,
-                tuple_repetition: input.call(TupleRepetition::parse_as_where_predicate)?,
+                items: WhereItem::parse_list(input)?,
             })
         } else {
             Err(lookahead1.error())
@@ -546,17 +1148,97 @@ impl ForTuplesMacro {
         }
     }
 
+    /// Try to parse the given macro as `Self` at a type position.
+    ///
+    /// At a type position the repetition is parsed as a type, optionally wrapped in parenthesis.
+    ///
+    /// Returns `Ok(None)` if it is not a `for_tuples!` macro.
+    fn try_from_type(macro_item: &Macro) -> Result<Option<Self>> {
+        // Not the macro we are searching for
+        if !macro_item.path.is_ident("for_tuples") {
+            return Ok(None);
+        }
+
+        macro_item.parse_body_with(Self::parse_type).map(Some)
+    }
+
+    /// Parse `Self` at a type position.
+    fn parse_type(input: ParseStream) -> Result<Self> {
+        let lookahead1 = input.lookahead1();
+
+        if lookahead1.peek(token::Paren) {
+            let content;
+            Ok(ForTuplesMacro::TypeParenthesized {
+                paren_token: parenthesized!(content in input),
+                tuple_repetition: content.call(TupleRepetition::parse_as_type)?,
+            })
+        } else if lookahead1.peek(token::Pound) {
+            Ok(ForTuplesMacro::Type {
+                tuple_repetition: input.call(TupleRepetition::parse_as_type)?,
+            })
+        } else {
+            Err(lookahead1.error())
+        }
+    }
+
+    /// Try to parse the given macro as `Self` at a pattern position.
+    ///
+    /// At a pattern position the repetition is parsed as a pattern, optionally wrapped in
+    /// parenthesis.
+    ///
+    /// Returns `Ok(None)` if it is not a `for_tuples!` macro.
+    fn try_from_pat(macro_item: &Macro) -> Result<Option<Self>> {
+        // Not the macro we are searching for
+        if !macro_item.path.is_ident("for_tuples") {
+            return Ok(None);
+        }
+
+        macro_item.parse_body_with(Self::parse_pat).map(Some)
+    }
+
+    /// Parse `Self` at a pattern position.
+    fn parse_pat(input: ParseStream) -> Result<Self> {
+        let lookahead1 = input.lookahead1();
+
+        if lookahead1.peek(token::Paren) {
+            let content;
+            Ok(ForTuplesMacro::PatParenthesized {
+                paren_token: parenthesized!(content in input),
+                tuple_repetition: content.call(TupleRepetition::parse_as_pat)?,
+            })
+        } else if lookahead1.peek(token::Pound) {
+            Ok(ForTuplesMacro::Pat {
+                tuple_repetition: input.call(TupleRepetition::parse_as_pat)?,
+            })
+        } else {
+            Err(lookahead1.error())
+        }
+    }
+
     /// Is this a custom where clause?
     fn is_where(&self) -> bool {
         matches!(self, Self::Where { .. })
     }
 
-    /// Convert this into the where clause tuple repetition.
-    fn into_where(self) -> Option<TupleRepetition> {
+    /// Convert this into the repeated type of a type repetition wrapped in parenthesis.
+    ///
+    /// Only this form is a tuple type, e.g. `for_tuples!( ( #( Option<Tuple> ),* ) )`.
+    fn into_repeated_type(self) -> Option<Type> {
         match self {
-            Self::Where {
+            Self::TypeParenthesized {
                 tuple_repetition, ..
-            } => Some(tuple_repetition),
+            } => match tuple_repetition.repetition {
+                Repetition::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Convert this into the items of the custom where clause.
+    fn into_where(self) -> Option<Vec<WhereItem>> {
+        match self {
+            Self::Where { items, .. } => Some(items),
             _ => None,
         }
     }
@@ -564,40 +1246,43 @@ impl ForTuplesMacro {
     /// Expand `self` to the actual implementation without the `for_tuples!` macro.
     ///
     /// This will unroll the repetition by replacing the placeholder identifier in each iteration
-    /// with the one given in `tuples`. If `use_self` is `true`, the tuple will be access by using
-    /// `self.x`.
+    /// with the one given in `tuples`. The elements are accessed as given by `access`, while
+    /// `self_access` defines if they can be accessed through `self`, e.g. `self.x`. `declared_idents` are the
+    /// idents that are derived per tuple element, see [`derive_ident_from_placeholder`].
     ///
     /// Returns the generated code.
     fn expand(
         self,
         tuple_placeholder_ident: &Ident,
         tuples: &[Ident],
-        use_self: bool,
-    ) -> TokenStream {
-        match self {
+        access: &ElementAccess,
+        self_access: SelfAccess,
+        declared_idents: &mut Vec<Ident>,
+    ) -> Result<TokenStream> {
+        Ok(match self {
             Self::ItemType {
                 type_token,
                 ident,
+                generics,
                 equal_token,
-                paren_token,
-                tuple_repetition,
+                ty,
                 semi_token,
             } => {
-                let mut token_stream = type_token.to_token_stream();
-                let repetition =
-                    tuple_repetition.expand_as_type_declaration(tuple_placeholder_ident, tuples);
-
-                match repetition {
-                    Ok(rep) => {
-                        ident.to_tokens(&mut token_stream);
-                        equal_token.to_tokens(&mut token_stream);
-                        paren_token.surround(&mut token_stream, |tokens| tokens.extend(rep));
-                        semi_token.to_tokens(&mut token_stream);
-                    }
-                    Err(e) => token_stream.extend(e.to_compile_error()),
+                let ty = expand_repetitions_in_tokens(ty, tuple_placeholder_ident, tuples, access)?;
+
+                // A where clause in front of `=` is deprecated, so all predicates are put into the
+                // where clause after the type.
+                let (ty, trailing_where_clause) = split_trailing_where_clause(ty)?;
+                let mut generics = generics;
+                if let Some(trailing) = trailing_where_clause {
+                    generics
+                        .make_where_clause()
+                        .predicates
+                        .extend(trailing.predicates);
                 }
+                let where_clause = &generics.where_clause;
 
-                token_stream
+                quote!( #type_token #ident #generics #equal_token #ty #where_clause #semi_token )
             }
             Self::ItemConst {
                 const_token,
@@ -608,61 +1293,518 @@ impl ForTuplesMacro {
                 expr,
                 semi_token,
             } => {
-                let mut token_stream = const_token.to_token_stream();
-
-                let expr = expr.expand(tuple_placeholder_ident, tuples, use_self);
-
-                match expr {
-                    Ok(expr) => {
-                        ident.to_tokens(&mut token_stream);
-                        colon_token.to_tokens(&mut token_stream);
-                        const_type.to_tokens(&mut token_stream);
-                        equal_token.to_tokens(&mut token_stream);
-                        token_stream.extend(expr);
-                        semi_token.to_tokens(&mut token_stream);
+                let expr =
+                    expand_repetitions_in_tokens(expr, tuple_placeholder_ident, tuples, access)?;
+
+                quote!( #const_token #ident #colon_token #const_type #equal_token #expr #semi_token )
+            }
+            Self::StmtParenthesized {
+                paren_token,
+                tuple_repetition,
+            } => {
+                let repetition = tuple_repetition.expand_as_stmts(
+                    tuple_placeholder_ident,
+                    tuples,
+                    access,
+                    self_access,
+                    declared_idents,
+                )?;
+
+                let mut token_stream = TokenStream::new();
+                paren_token.surround(&mut token_stream, |tokens| tokens.extend(repetition));
+                token_stream
+            }
+            Self::Stmt { tuple_repetition } => tuple_repetition.expand_as_stmts(
+                tuple_placeholder_ident,
+                tuples,
+                access,
+                self_access,
+                declared_idents,
+            )?,
+            Self::SelfConstruction {
+                self_token,
+                tuple_repetition,
+                ..
+            } if !matches!(access, ElementAccess::Tuple) => {
+                let elements = tuple_repetition.expand_as_stmts_per_element(
+                    tuple_placeholder_ident,
+                    tuples,
+                    access,
+                    self_access,
+                    declared_idents,
+                )?;
+
+                match access {
+                    // The elements are the fields of a struct, so construct it field by field.
+                    ElementAccess::Members(members) => {
+                        quote!( #self_token { #( #members: #elements ),* } )
                     }
-                    Err(e) => token_stream.extend(e.to_compile_error()),
+                    _ => quote!( [ #( #elements ),* ] ),
                 }
+            }
+            Self::SelfConstruction {
+                self_token,
+                paren_token,
+                mut tuple_repetition,
+            } => {
+                // Always separate the elements by `,` to get a well-formed tuple, as `,` is also
+                // added after the last element, a single element results in `(value,)`.
+                tuple_repetition.separator = Some(Separator::Comma(token::Comma(self_token.span)));
 
+                let repetition = tuple_repetition.expand_as_stmts(
+                    tuple_placeholder_ident,
+                    tuples,
+                    access,
+                    self_access,
+                    declared_idents,
+                )?;
+
+                let mut token_stream = TokenStream::new();
+                paren_token.surround(&mut token_stream, |tokens| tokens.extend(repetition));
                 token_stream
             }
-            Self::StmtParenthesized {
+            Self::TypeParenthesized {
                 paren_token,
                 tuple_repetition,
             } => {
-                let mut token_stream = TokenStream::new();
-                let repetition =
-                    tuple_repetition.expand_as_stmts(tuple_placeholder_ident, tuples, use_self);
+                let repetition = tuple_repetition.expand_as_type_declaration(
+                    tuple_placeholder_ident,
+                    tuples,
+                    declared_idents,
+                )?;
 
-                match repetition {
-                    Ok(rep) => paren_token.surround(&mut token_stream, |tokens| tokens.extend(rep)),
-                    Err(e) => token_stream.extend(e.to_compile_error()),
+                let mut token_stream = TokenStream::new();
+                paren_token.surround(&mut token_stream, |tokens| tokens.extend(repetition));
+                token_stream
+            }
+            Self::Type { tuple_repetition } => tuple_repetition.expand_as_type_declaration(
+                tuple_placeholder_ident,
+                tuples,
+                declared_idents,
+            )?,
+            Self::PatParenthesized {
+                tuple_repetition, ..
+            } if !matches!(access, ElementAccess::Tuple) => {
+                let elements = tuple_repetition.expand_as_pats_per_element(
+                    tuple_placeholder_ident,
+                    tuples,
+                    declared_idents,
+                )?;
+
+                match access {
+                    // The elements are the fields of a struct, so destructure it field by field.
+                    ElementAccess::Members(members) => {
+                        quote!( Self { #( #members: #elements ),* } )
+                    }
+                    _ => quote!( [ #( #elements ),* ] ),
                 }
+            }
+            Self::PatParenthesized {
+                paren_token,
+                tuple_repetition,
+            } => {
+                let repetition = tuple_repetition.expand_as_pats(
+                    tuple_placeholder_ident,
+                    tuples,
+                    declared_idents,
+                )?;
 
+                let mut token_stream = TokenStream::new();
+                paren_token.surround(&mut token_stream, |tokens| tokens.extend(repetition));
                 token_stream
             }
-            Self::Stmt { tuple_repetition } => tuple_repetition
-                .expand_as_stmts(tuple_placeholder_ident, tuples, use_self)
-                .unwrap_or_else(|e| e.to_compile_error()),
+            Self::Pat { tuple_repetition } => {
+                tuple_repetition.expand_as_pats(tuple_placeholder_ident, tuples, declared_idents)?
+            }
             Self::Where { .. } => TokenStream::new(),
+        })
+    }
+}
+
+/// The `#[for_tuples(..)]` attribute syntax of a method.
+enum ForTuplesAttribute {
+    /// Generic parameters, e.g. `#[for_tuples(<#( Tuple_T: From<Tuple> ),*>)]`.
+    Generics(Vec<TupleRepetition>),
+    /// A custom where clause, e.g. `#[for_tuples(where #( Tuple: Trait )*)]`.
+    Where(Vec<WhereItem>),
+}
+
+impl Parse for ForTuplesAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead1 = input.lookahead1();
+
+        if lookahead1.peek(token::Lt) {
+            input.parse::<token::Lt>()?;
+
+            let mut repetitions = Vec::new();
+            while !input.peek(token::Gt) {
+                repetitions.push(input.call(TupleRepetition::parse_as_generic_param)?);
+
+                if input.peek(token::Comma) {
+                    input.parse::<token::Comma>()?;
+                }
+            }
+
+            input.parse::<token::Gt>()?;
+            Ok(Self::Generics(repetitions))
+        } else if lookahead1.peek(token::Where) {
+            input.parse::<token::Where>()?;
+            Ok(Self::Where(WhereItem::parse_list(input)?))
+        } else {
+            Err(lookahead1.error())
+        }
+    }
+}
+
+impl ForTuplesAttribute {
+    /// Expand `self` into the given `generics`.
+    fn expand_to_generics(
+        self,
+        tuple_placeholder_ident: &Ident,
+        tuples: &[Ident],
+        declared_idents: &mut Vec<Ident>,
+        generics: &mut Generics,
+    ) -> Result<()> {
+        match self {
+            Self::Generics(repetitions) => repetitions.into_iter().try_for_each(|rep| {
+                generics.params.extend(rep.expand_as_generic_params(
+                    tuple_placeholder_ident,
+                    tuples,
+                    declared_idents,
+                )?);
+                Ok(())
+            }),
+            Self::Where(items) => items.into_iter().try_for_each(|item| {
+                item.expand_to_where_clause(
+                    tuple_placeholder_ident,
+                    tuples,
+                    declared_idents,
+                    generics.make_where_clause(),
+                )
+            }),
         }
     }
 }
 
 /// Add the tuple elements as generic parameters to the given trait implementation.
+///
+/// `bound` returns the bound for the element at the given index.
 fn add_tuple_elements_generics(
     tuples: &[Ident],
     mut trait_impl: ItemImpl,
-    bound: Option<TokenStream>,
+    bound: impl Fn(usize) -> Option<TokenStream>,
 ) -> Result<ItemImpl> {
-    crate::utils::add_tuple_element_generics(tuples, bound, &mut trait_impl.generics);
+    tuples.iter().enumerate().for_each(|(index, tuple)| {
+        crate::utils::add_tuple_element_generics(
+            std::slice::from_ref(tuple),
+            bound(index),
+            &mut trait_impl.generics,
+        )
+    });
     Ok(trait_impl)
 }
 
+/// Add the bounds of the tuple elements as where predicates to the given trait implementation.
+///
+/// `bound` returns the bound for the element at the given index.
+fn add_tuple_elements_bounds(
+    tuples: &[Ident],
+    mut trait_impl: ItemImpl,
+    bound: impl Fn(usize) -> Option<TokenStream>,
+) -> ItemImpl {
+    tuples.iter().enumerate().for_each(|(index, tuple)| {
+        if let Some(bound) = bound(index) {
+            trait_impl
+                .generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#tuple: #bound));
+        }
+    });
+    trait_impl
+}
+
+mod keyword {
+    syn::custom_keyword!(last);
+}
+
+/// The index of a tuple element in `#[tuple_types_custom_trait_bound(INDEX: BOUNDS)]`.
+enum ElementIndex {
+    /// The element at the given index.
+    Index(usize),
+    /// The last element of the tuple.
+    Last,
+}
+
+impl ElementIndex {
+    /// Does `self` point to the element at `index` in a tuple with `len` elements?
+    fn matches(&self, index: usize, len: usize) -> bool {
+        match self {
+            Self::Index(i) => *i == index,
+            Self::Last => index + 1 == len,
+        }
+    }
+
+    /// Returns the index `self` points to in a tuple with `len` elements.
+    fn resolve(&self, len: usize) -> Option<usize> {
+        match self {
+            Self::Index(i) => Some(*i).filter(|i| *i < len),
+            Self::Last => len.checked_sub(1),
+        }
+    }
+}
+
+/// The arguments of `#[tuple_types_custom_trait_bound(BOUNDS, INDEX: BOUNDS, last: BOUNDS)]`.
+///
+/// The bounds without an index are used for all elements without an override. Every part is
+/// optional, but at least one part needs to be given.
+#[derive(Default)]
+struct CustomTraitBound {
+    /// The bounds for all elements without an override.
+    default: Option<Punctuated<TypeParamBound, token::Plus>>,
+    /// The bounds that override the default bounds of a specific element.
+    ///
+    /// The span points to the index of the override.
+    overrides: Vec<(ElementIndex, Span, Punctuated<TypeParamBound, token::Plus>)>,
+}
+
+impl CustomTraitBound {
+    /// Is the next part of `input` an override, aka `INDEX: BOUNDS` or `last: BOUNDS`?
+    fn peek_override(input: ParseStream) -> bool {
+        (input.peek(LitInt) || input.peek(keyword::last))
+            && input.peek2(token::Colon)
+            && !input.peek2(token::PathSep)
+    }
+
+    /// Returns the override of the element at `index` in a tuple with `len` elements.
+    fn override_for(&self, index: usize, len: usize) -> Option<TokenStream> {
+        self.overrides
+            .iter()
+            .find(|(i, _, _)| i.matches(index, len))
+            .map(|(_, _, bounds)| bounds.to_token_stream())
+    }
+
+    /// Check that the overrides apply to the tuples with the given `arities`.
+    ///
+    /// Returns an error for an index that is out of range for all tuples and for overrides that
+    /// point to the same element of a tuple.
+    fn check_overrides(&self, arities: &[usize]) -> Result<()> {
+        let max = arities.iter().copied().max().unwrap_or(0);
+
+        for (n, (index, span, _)) in self.overrides.iter().enumerate() {
+            if let ElementIndex::Index(i) = index {
+                if *i >= max {
+                    return Err(Error::new(
+                        *span,
+                        format!(
+                            "Index `{}` is out of range, there are at most {} tuple types.",
+                            i, max
+                        ),
+                    ));
+                }
+            }
+
+            let conflict = arities.iter().find_map(|&len| {
+                let resolved = index.resolve(len)?;
+                self.overrides[..n]
+                    .iter()
+                    .any(|(other, _, _)| other.resolve(len) == Some(resolved))
+                    .then_some((resolved, len))
+            });
+
+            if let Some((resolved, len)) = conflict {
+                return Err(Error::new(
+                    *span,
+                    format!(
+                        "Conflicting overrides, both point to the tuple type at index {} for a \
+                         tuple of length {}.",
+                        resolved, len
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for CustomTraitBound {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let default = if Self::peek_override(input) {
+            None
+        } else {
+            Some(Punctuated::parse_separated_nonempty(input)?)
+        };
+        let mut overrides = Vec::new();
+
+        loop {
+            if default.is_some() || !overrides.is_empty() {
+                if input.is_empty() {
+                    break;
+                }
+
+                input.parse::<token::Comma>()?;
+
+                if input.is_empty() {
+                    break;
+                }
+            }
+
+            if !Self::peek_override(input) {
+                return Err(input.error("expected `INDEX: BOUNDS` or `last: BOUNDS`"));
+            }
+
+            let span = input.span();
+            let index = if input.peek(keyword::last) {
+                input.parse::<keyword::last>()?;
+                ElementIndex::Last
+            } else {
+                ElementIndex::Index(input.parse::<LitInt>()?.base10_parse()?)
+            };
+            input.parse::<token::Colon>()?;
+
+            overrides.push((index, span, Punctuated::parse_separated_nonempty(input)?));
+        }
+
+        Ok(Self { default, overrides })
+    }
+}
+
+/// The bounds of the tuple types, as configured by the attributes of the trait implementation.
+#[derive(Default)]
+struct TupleElementBounds {
+    /// `#[tuple_types_no_default_trait_bound]` was given.
+    no_default: bool,
+    /// The bounds given by `#[tuple_types_custom_trait_bound(..)]`.
+    custom: Option<CustomTraitBound>,
+    /// The bounds given by all `#[tuple_types_extra_trait_bound(..)]`.
+    extra: Vec<Punctuated<TypeParamBound, token::Plus>>,
+}
+
+impl TupleElementBounds {
+    /// Extract the bound attributes from `attrs`.
+    ///
+    /// Returns an error for duplicated or conflicting attributes.
+    fn extract(attrs: &mut Vec<Attribute>) -> Result<Self> {
+        let mut res = Self::default();
+        let mut no_default_attr = None;
+        let mut custom_attr = None;
+
+        for attr in std::mem::take(attrs) {
+            if attr.path().is_ident(TUPLE_TYPES_NO_DEFAULT_TRAIT_BOUND) {
+                if no_default_attr.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Duplicated `#[tuple_types_no_default_trait_bound]` attribute.",
+                    ));
+                }
+
+                res.no_default = true;
+                no_default_attr = Some(attr);
+            } else if attr.path().is_ident(TUPLE_TYPES_CUSTOM_TRAIT_BOUND) {
+                if custom_attr.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Duplicated `#[tuple_types_custom_trait_bound]` attribute.",
+                    ));
+                }
+
+                res.custom = Some(Self::parse_attribute(
+                    &attr,
+                    "Expected #[tuple_types_custom_trait_bound($trait_bounds)]",
+                    CustomTraitBound::parse,
+                )?);
+                custom_attr = Some(attr);
+            } else if attr.path().is_ident(TUPLE_TYPES_EXTRA_TRAIT_BOUND) {
+                res.extra.push(Self::parse_attribute(
+                    &attr,
+                    "Expected #[tuple_types_extra_trait_bound($trait_bounds)]",
+                    Punctuated::parse_separated_nonempty,
+                )?);
+            } else {
+                attrs.push(attr);
+            }
+        }
+
+        // Only the overrides can be combined with not having a default bound.
+        let has_default = res.custom.as_ref().is_some_and(|c| c.default.is_some());
+        if let (Some(_), Some(custom_attr), true) = (no_default_attr, custom_attr, has_default) {
+            return Err(Error::new(
+                custom_attr.span(),
+                "`#[tuple_types_custom_trait_bound]` with a default bound can not be combined \
+                 with `#[tuple_types_no_default_trait_bound]`.",
+            ));
+        }
+
+        Ok(res)
+    }
+
+    /// Parse the bounds given as arguments to `attr` with `parser`.
+    ///
+    /// `expected` is the error message if `attr` has no arguments.
+    fn parse_attribute<T>(
+        attr: &Attribute,
+        expected: &str,
+        parser: impl Parser<Output = T>,
+    ) -> Result<T> {
+        let Meta::List(items) = &attr.meta else {
+            return Err(Error::new(attr.span(), expected));
+        };
+
+        parser
+            .parse2(items.tokens.clone())
+            .map_err(|e| Error::new(e.span(), format!("Invalid trait bound: {}", e)))
+    }
+
+    /// Check the overrides for the tuples with the given `arities`, see
+    /// [`CustomTraitBound::check_overrides`].
+    fn check_overrides(&self, arities: &[usize]) -> Result<()> {
+        self.custom
+            .as_ref()
+            .map_or(Ok(()), |custom| custom.check_overrides(arities))
+    }
+
+    /// Returns `true` if the bound of any tuple type is overridden.
+    fn has_overrides(&self) -> bool {
+        self.custom
+            .as_ref()
+            .is_some_and(|custom| !custom.overrides.is_empty())
+    }
+
+    /// Returns the bound of the tuple type at `index` in a tuple with `len` elements.
+    ///
+    /// `default_trait` is the implemented trait, if there is any.
+    fn bound_for(
+        &self,
+        default_trait: Option<&Path>,
+        index: usize,
+        len: usize,
+    ) -> Option<TokenStream> {
+        let custom = self.custom.as_ref();
+        let bound = match custom.and_then(|c| c.override_for(index, len)) {
+            Some(bound) => Some(bound),
+            None if self.no_default => None,
+            None => match custom.and_then(|c| c.default.as_ref()) {
+                Some(default) => Some(quote!( #default )),
+                None => default_trait.map(|t| quote!( #t )),
+            },
+        };
+
+        let extra = &self.extra;
+        match bound {
+            Some(bound) => Some(quote!( #bound #( + #extra )* )),
+            None if extra.is_empty() => None,
+            None => Some(quote!( #( #extra )+* )),
+        }
+    }
+}
+
 /// Fold a given trait implementation into a tuple implementation of the given trait.
 struct ToTupleImplementation<'a> {
     /// The tuple idents to use while expanding the repetitions.
     tuples: &'a [Ident],
+    /// How the tuple elements are accessed.
+    access: &'a ElementAccess,
     /// The placeholder ident given by the user.
     ///
     /// This placeholder ident while be replaced in the expansion with the correct tuple identifiers.
@@ -671,8 +1813,13 @@ struct ToTupleImplementation<'a> {
     errors: Vec<Error>,
     /// This is set to `true`, when folding in a function block that has a `self` parameter.
     has_self_parameter: bool,
-    /// A custom where clause provided by the user.
-    custom_where_clause: Option<TupleRepetition>,
+    /// The custom where clauses provided by the user.
+    custom_where_clauses: Vec<WhereItem>,
+    /// The self type of the implementation.
+    self_type: &'a TupleSelfType,
+    /// The idents declared inside repetitions of the current function that are derived per
+    /// tuple element, see [`derive_ident_from_placeholder`].
+    declared_idents: Vec<Ident>,
 }
 
 impl<'a> ToTupleImplementation<'a> {
@@ -681,84 +1828,70 @@ impl<'a> ToTupleImplementation<'a> {
         trait_impl: &ItemImpl,
         tuple_placeholder_ident: &'a Ident,
         tuples: &'a [Ident],
-        ref_tuples: bool,
+        access: &'a ElementAccess,
+        self_type: &'a TupleSelfType,
     ) -> Result<TokenStream> {
         let mut to_tuple = ToTupleImplementation {
             tuples,
+            access,
             errors: Vec::new(),
             tuple_placeholder_ident,
             has_self_parameter: false,
-            custom_where_clause: None,
+            custom_where_clauses: Vec::new(),
+            self_type,
+            declared_idents: Vec::new(),
         };
 
         let mut res = fold::fold_item_impl(&mut to_tuple, trait_impl.clone());
 
-        let default_trait = trait_impl.trait_.clone().map(|t| t.1).ok_or_else(|| {
-            Error::new(
-                trait_impl.span(),
-                "The semi-automatic implementation is required to implement a trait!",
-            )
-        })?;
+        // Inherent implementations don't have a default trait bound.
+        let default_trait = trait_impl.trait_.as_ref().map(|t| &t.1);
 
-        // Check if we should customize the trait bound
-        let trait_ = if let Some(pos) = res
-            .attrs
-            .iter()
-            .position(|a| a.path().is_ident(TUPLE_TYPES_CUSTOM_TRAIT_BOUND))
-        {
-            // Parse custom trait bound
-            let attr = &res.attrs[pos];
-            let Meta::List(items) = &attr.meta else {
-                return Err(Error::new(
-                    attr.span(),
-                    "Expected #[tuple_types_custom_trait_bound($trait_bounds)]",
-                ));
-            };
+        // Collect the attributes that customize the bounds of the tuple types
+        let bounds = TupleElementBounds::extract(&mut res.attrs)?;
 
-            let input = items.tokens.to_token_stream();
-            let result = syn::parse2::<syn::TypeTraitObject>(input);
-            let trait_name = match result {
-                Ok(bounds) => bounds,
-                Err(e) => {
+        let bound = |index| bounds.bound_for(default_trait, index, tuples.len());
+        let mut res = match self_type {
+            // Add the bounds of the field types
+            TupleSelfType::Fields(_) => add_tuple_elements_bounds(tuples, res, bound),
+            TupleSelfType::Array(element) => {
+                if bounds.has_overrides() {
                     return Err(Error::new(
-                        e.span(),
-                        format!("Invalid trait bound: {}", e.to_string()),
-                    ))
+                        trait_impl.impl_token.span,
+                        "Per-index trait bounds are not supported together with `also_arrays`.",
+                    ));
                 }
-            };
 
-            res.attrs.remove(pos);
-            quote! { #trait_name }
-        } else {
-            quote! { #default_trait }
+                // All elements share the same type, so add just one generic
+                add_tuple_elements_generics(std::slice::from_ref(element), res, |_| {
+                    bounds.bound_for(default_trait, 0, 1)
+                })?
+            }
+            // Add the tuple generics
+            _ => add_tuple_elements_generics(tuples, res, bound)?,
         };
+        // Add the correct self type
+        *res.self_ty = self_type.expand(tuple_placeholder_ident, tuples)?;
 
-        // Check if we should add the bound to the implemented trait for each tuple type.
-        let add_bound = if let Some(pos) = res
-            .attrs
-            .iter()
-            .position(|a| a.path().is_ident(TUPLE_TYPES_NO_DEFAULT_TRAIT_BOUND))
-        {
-            res.attrs.remove(pos);
-            None
-        } else {
-            Some(trait_)
-        };
+        // The lifetime of a reference is not used by the implementation for `()`.
+        if matches!(self_type, TupleSelfType::Reference(r) if r.lifetime.is_some()) {
+            res.attrs
+                .push(parse_quote!(#[allow(clippy::extra_unused_lifetimes)]));
+        }
 
-        // Add the tuple generics
-        let mut res = add_tuple_elements_generics(tuples, res, add_bound)?;
-        // Add the correct self type
-        if ref_tuples {
-            res.self_ty = parse_quote!( ( #( &#tuples, )* ) );
-        } else {
-            res.self_ty = parse_quote!( ( #( #tuples, )* ) );
-        };
-        res.attrs.push(parse_quote!(#[allow(unused)]));
+        // Expressions over the tuple elements are `()` for the implementation for `()`.
+        if tuples.is_empty() {
+            res.attrs.push(parse_quote!(#[allow(clippy::unused_unit)]));
+        }
+
+        res.attrs
+            .push(parse_quote!(#[allow(unused, non_snake_case, non_camel_case_types)]));
 
-        if let Some(where_clause) = to_tuple.custom_where_clause.take() {
-            where_clause.expand_to_where_clause(
+        for item in std::mem::take(&mut to_tuple.custom_where_clauses) {
+            item.expand_to_where_clause(
                 tuple_placeholder_ident,
                 tuples,
+                &mut to_tuple.declared_idents,
                 res.generics.make_where_clause(),
             )?;
         }
@@ -773,16 +1906,51 @@ impl<'a> ToTupleImplementation<'a> {
         }
     }
 
+    /// Expand the given `for_tuples!` macro, see [`ForTuplesMacro::expand`].
+    ///
+    /// Errors are collected, so the whole implementation fails instead of just the item.
+    fn expand_for_tuples(&mut self, for_tuples: ForTuplesMacro, use_self: bool) -> TokenStream {
+        // `Self( .. )` constructs the tuple elements, so `Self` needs to consist of them.
+        if let ForTuplesMacro::SelfConstruction { self_token, .. } = &for_tuples {
+            if matches!(
+                self.self_type,
+                TupleSelfType::Reference(_) | TupleSelfType::Wrapper(_)
+            ) {
+                self.errors.push(Error::new(
+                    self_token.span,
+                    "`Self` does not consist of the tuple elements for this self type. \
+                     Use `for_tuples!( ( #( .. ),* ) )` to construct the tuple instead.",
+                ));
+                return TokenStream::new();
+            }
+        }
+
+        let self_access = match self.self_type {
+            _ if !use_self => SelfAccess::None,
+            TupleSelfType::Wrapper(_) => SelfAccess::Wrapped,
+            _ => SelfAccess::Tuple,
+        };
+
+        for_tuples
+            .expand(
+                self.tuple_placeholder_ident,
+                self.tuples,
+                self.access,
+                self_access,
+                &mut self.declared_idents,
+            )
+            .unwrap_or_else(|e| {
+                self.errors.push(e);
+                TokenStream::new()
+            })
+    }
+
     /// Fold the expr and returns the folded expr and if it was a `for_tuples!`.
     fn custom_fold_expr(&mut self, expr: Expr) -> (Expr, bool) {
         match expr {
             Expr::Macro(expr_macro) => match ForTuplesMacro::try_from(&expr_macro.mac, false) {
                 Ok(Some(for_tuples)) => (
-                    Expr::Verbatim(for_tuples.expand(
-                        &self.tuple_placeholder_ident,
-                        self.tuples,
-                        self.has_self_parameter,
-                    )),
+                    Expr::Verbatim(self.expand_for_tuples(for_tuples, self.has_self_parameter)),
                     true,
                 ),
                 Ok(None) => (fold::fold_expr_macro(self, expr_macro).into(), false),
@@ -802,22 +1970,12 @@ impl<'a> Fold for ToTupleImplementation<'a> {
             ImplItem::Macro(macro_item) => match ForTuplesMacro::try_from(&macro_item.mac, true) {
                 Ok(Some(for_tuples)) => {
                     if for_tuples.is_where() {
-                        if self.custom_where_clause.is_some() {
-                            self.errors.push(Error::new(
-                                macro_item.span(),
-                                "Only one custom where clause is supported!",
-                            ));
-                        } else {
-                            self.custom_where_clause = for_tuples.into_where();
-                        }
+                        self.custom_where_clauses
+                            .extend(for_tuples.into_where().into_iter().flatten());
 
                         ImplItem::Verbatim(Default::default())
                     } else {
-                        ImplItem::Verbatim(for_tuples.expand(
-                            &self.tuple_placeholder_ident,
-                            self.tuples,
-                            false,
-                        ))
+                        ImplItem::Verbatim(self.expand_for_tuples(for_tuples, false))
                     }
                 }
                 Ok(None) => fold::fold_impl_item_macro(self, macro_item).into(),
@@ -861,12 +2019,8 @@ impl<'a> Fold for ToTupleImplementation<'a> {
 
     fn fold_type(&mut self, ty: Type) -> Type {
         match ty {
-            Type::Macro(ty_macro) => match ForTuplesMacro::try_from(&ty_macro.mac, false) {
-                Ok(Some(for_tuples)) => Type::Verbatim(for_tuples.expand(
-                    &self.tuple_placeholder_ident,
-                    self.tuples,
-                    false,
-                )),
+            Type::Macro(ty_macro) => match ForTuplesMacro::try_from_type(&ty_macro.mac) {
+                Ok(Some(for_tuples)) => Type::Verbatim(self.expand_for_tuples(for_tuples, false)),
                 Ok(None) => fold::fold_type_macro(self, ty_macro).into(),
                 Err(e) => {
                     self.errors.push(e);
@@ -877,17 +2031,51 @@ impl<'a> Fold for ToTupleImplementation<'a> {
         }
     }
 
+    fn fold_pat(&mut self, pat: Pat) -> Pat {
+        match pat {
+            Pat::Macro(pat_macro) => match ForTuplesMacro::try_from_pat(&pat_macro.mac) {
+                Ok(Some(for_tuples)) => Pat::Verbatim(self.expand_for_tuples(for_tuples, false)),
+                Ok(None) => Pat::Macro(fold::fold_expr_macro(self, pat_macro)),
+                Err(e) => {
+                    self.errors.push(e);
+                    Pat::Verbatim(Default::default())
+                }
+            },
+            _ => fold::fold_pat(self, pat),
+        }
+    }
+
     fn fold_impl_item_fn(&mut self, mut impl_item_method: ImplItemFn) -> ImplItemFn {
         let has_self = impl_item_method
             .sig
             .inputs
             .first()
-            .map(|a| match a {
-                FnArg::Receiver(_) => true,
-                _ => false,
-            })
+            .map(|a| matches!(a, FnArg::Receiver(_)))
             .unwrap_or(false);
 
+        // The idents declared in the function are not visible outside of it
+        let old_declared_idents = std::mem::take(&mut self.declared_idents);
+
+        let (for_tuples_attrs, attrs) = impl_item_method
+            .attrs
+            .into_iter()
+            .partition::<Vec<_>, _>(|a| a.path().is_ident(FOR_TUPLES_ATTRIBUTE));
+        impl_item_method.attrs = attrs;
+
+        // Expand the attributes first, as they declare the generic parameters used in the signature
+        for attr in for_tuples_attrs {
+            if let Err(e) = attr.parse_args::<ForTuplesAttribute>().and_then(|attr| {
+                attr.expand_to_generics(
+                    self.tuple_placeholder_ident,
+                    self.tuples,
+                    &mut self.declared_idents,
+                    &mut impl_item_method.sig.generics,
+                )
+            }) {
+                self.errors.push(e);
+            }
+        }
+
         impl_item_method.sig = fold::fold_signature(self, impl_item_method.sig);
 
         // Store the old value and set the current one
@@ -896,54 +2084,399 @@ impl<'a> Fold for ToTupleImplementation<'a> {
 
         impl_item_method.block = fold::fold_block(self, impl_item_method.block);
         self.has_self_parameter = old_has_self_parameter;
+        self.declared_idents = old_declared_idents;
 
         impl_item_method
     }
 }
 
+/// The self type of the trait implementation that contains the tuple placeholder.
+enum TupleSelfType {
+    /// Just the placeholder, e.g. `Tuple`.
+    Tuple,
+    /// A reference to the placeholder, e.g. `&'a mut Tuple`.
+    ///
+    /// The reference is applied to each tuple element.
+    Reference(TypeReference),
+    /// Any other type that contains the placeholder, e.g. `Box<Tuple>` or `[Tuple; 2]`.
+    ///
+    /// The placeholder is replaced by the tuple type.
+    Wrapper(Type),
+    /// A `for_tuples!` repetition that wraps each tuple element, e.g.
+    /// `for_tuples!( ( #( Option<Tuple> ),* ) )`.
+    Repetition(Macro),
+    /// A struct whose fields are the tuple elements.
+    ///
+    /// The tuple elements are the concrete field types, so no generics are added for them.
+    Fields(Type),
+    /// An array of the given element type, e.g. `[T; 3]`.
+    ///
+    /// All tuple elements are replaced by the element type, so only one generic is added.
+    Array(Ident),
+}
+
+impl TupleSelfType {
+    /// Generate the self type for the given `tuples`.
+    fn expand(&self, tuple_placeholder_ident: &Ident, tuples: &[Ident]) -> Result<Type> {
+        Ok(match self {
+            Self::Tuple => parse_quote!( ( #( #tuples, )* ) ),
+            Self::Reference(reference) => {
+                let tuples = tuples.iter().map(|tuple| {
+                    let mut reference = reference.clone();
+                    reference.elem = Box::new(parse_quote!(#tuple));
+                    reference
+                });
+                parse_quote!( ( #( #tuples, )* ) )
+            }
+            Self::Wrapper(ty) => {
+                let mut replace = ReplacePlaceholderType {
+                    tuple_placeholder_ident,
+                    tuple: parse_quote!( ( #( #tuples, )* ) ),
+                };
+                replace.fold_type(ty.clone())
+            }
+            Self::Repetition(mac) => ForTuplesMacro::try_from_type(mac)?
+                .ok_or_else(|| Error::new(mac.span(), "Expected a `for_tuples!` repetition."))?
+                .expand(
+                    tuple_placeholder_ident,
+                    tuples,
+                    &ElementAccess::Tuple,
+                    SelfAccess::None,
+                    &mut Vec::new(),
+                )
+                .map(Type::Verbatim)?,
+            Self::Fields(ty) => ty.clone(),
+            Self::Array(element) => {
+                let len = tuples.len();
+                parse_quote!( [#element; #len] )
+            }
+        })
+    }
+}
+
+/// Replaces the tuple placeholder in a type with the actual tuple type.
+struct ReplacePlaceholderType<'a> {
+    tuple_placeholder_ident: &'a Ident,
+    tuple: Type,
+}
+
+impl<'a> Fold for ReplacePlaceholderType<'a> {
+    fn fold_type(&mut self, ty: Type) -> Type {
+        match ty {
+            Type::Path(ref path)
+                if path.qself.is_none() && path.path.is_ident(self.tuple_placeholder_ident) =>
+            {
+                self.tuple.clone()
+            }
+            _ => fold::fold_type(self, ty),
+        }
+    }
+}
+
+/// The primitive types, that are never taken as tuple placeholder.
+const PRIMITIVE_TYPES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+/// Collects all types in a type that consist of just an ident, except the primitive types.
+///
+/// These are the candidates for the tuple placeholder.
+#[derive(Default)]
+struct CollectIdentTypes {
+    idents: Vec<Ident>,
+}
+
+impl<'ast> Visit<'ast> for CollectIdentTypes {
+    fn visit_type_path(&mut self, path: &'ast TypePath) {
+        match path.path.get_ident() {
+            Some(ident) if path.qself.is_none() => {
+                if !self.idents.contains(ident) && !PRIMITIVE_TYPES.iter().any(|p| ident == p) {
+                    self.idents.push(ident.clone());
+                }
+            }
+            _ => visit::visit_type_path(self, path),
+        }
+    }
+}
+
 /// Extracts the tuple placeholder ident from the given trait implementation.
-fn extract_tuple_placeholder_ident(trait_impl: &ItemImpl) -> Result<(bool, Ident)> {
+fn extract_tuple_placeholder_ident(trait_impl: &ItemImpl) -> Result<(TupleSelfType, Ident)> {
     match *trait_impl.self_ty {
         Type::Reference(ref type_ref) => {
             if let Type::Path(ref type_path) = *type_ref.elem {
                 if let Some(ident) = type_path.path.get_ident() {
-                    return Ok((true, ident.clone()));
+                    return Ok((TupleSelfType::Reference(type_ref.clone()), ident.clone()));
                 }
             }
         }
         Type::Path(ref type_path) => {
             if let Some(ident) = type_path.path.get_ident() {
-                return Ok((false, ident.clone()));
+                return Ok((TupleSelfType::Tuple, ident.clone()));
+            }
+        }
+        Type::Macro(ref type_macro) => {
+            if let Some(for_tuples) = ForTuplesMacro::try_from_type(&type_macro.mac)? {
+                let ty = for_tuples.into_repeated_type().ok_or_else(|| {
+                    Error::new(
+                        type_macro.span(),
+                        "Expected a repetition wrapped in parenthesis as self type, e.g. \
+                         `for_tuples!( ( #( Option<Tuple> ),* ) )`.",
+                    )
+                })?;
+
+                return find_tuple_placeholder_ident(&ty)?
+                    .map(|ident| (TupleSelfType::Repetition(type_macro.mac.clone()), ident))
+                    .ok_or_else(|| {
+                        Error::new(
+                            ty.span(),
+                            "Expected a type that contains the `Tuple` placeholder.",
+                        )
+                    });
             }
         }
         _ => {}
     }
 
-    Err(Error::new(
-        trait_impl.self_ty.span(),
-        "Expected an `Ident` as tuple placeholder.",
-    ))
+    // The placeholder is wrapped by another type, e.g. `Box<Tuple>`.
+    find_tuple_placeholder_ident(&trait_impl.self_ty)?
+        .map(|ident| (TupleSelfType::Wrapper((*trait_impl.self_ty).clone()), ident))
+        .ok_or_else(|| {
+            Error::new(
+                trait_impl.self_ty.span(),
+                "Expected an `Ident` as tuple placeholder or a type that wraps the `Tuple` placeholder.",
+            )
+        })
+}
+
+/// Find the tuple placeholder ident in the given type.
+///
+/// Returns an error if there are multiple candidates, e.g. `Wrapper<String, Tuple>`. Then the
+/// other types need to be given as path with multiple segments, e.g. `std::string::String`.
+fn find_tuple_placeholder_ident(ty: &Type) -> Result<Option<Ident>> {
+    let mut collect = CollectIdentTypes::default();
+    collect.visit_type(ty);
+
+    match &collect.idents[..] {
+        [] => Ok(None),
+        [ident] => Ok(Some(ident.clone())),
+        idents => Err(Error::new(
+            ty.span(),
+            format!(
+                "Ambiguous tuple placeholder, found {}. Write the other types as path with \
+                 multiple segments, e.g. `std::string::String`.",
+                idents
+                    .iter()
+                    .map(|i| format!("`{}`", i))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        )),
+    }
 }
 
 /// Generate the semi-automatic tuple implementations for a given trait implementation and the given tuples.
+///
+/// If `also_arrays` is `true`, the trait is also implemented for arrays with the same number of
+/// elements.
 pub fn semi_automatic_impl(
     trait_impl: ItemImpl,
     tuple_elements: Vec<Ident>,
-    min: Option<usize>,
+    arities: &[usize],
+    also_arrays: bool,
 ) -> Result<TokenStream> {
     let placeholder_ident = extract_tuple_placeholder_ident(&trait_impl)?;
 
+    // The bounds of `Self` need to be implemented by the generated tuples, this includes the
+    // predicates of custom where clauses.
+    let custom_where_predicates = trait_impl
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Macro(item) => ForTuplesMacro::try_from(&item.mac, true).ok().flatten(),
+            _ => None,
+        })
+        .filter_map(ForTuplesMacro::into_where)
+        .flatten()
+        .filter_map(|item| match item {
+            WhereItem::Predicate(predicate) => Some(predicate),
+            WhereItem::Repetition(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let self_bounds = trait_impl
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .chain(&custom_where_predicates)
+        .filter_map(|p| match p {
+            WherePredicate::Type(p) if p.bounded_ty == parse_quote!(Self) => Some(&p.bounds),
+            _ => None,
+        })
+        .flatten();
+    crate::utils::check_std_tuple_bounds(self_bounds, arities.iter().copied().max().unwrap_or(0))?;
+    TupleElementBounds::extract(&mut trait_impl.attrs.clone())?.check_overrides(arities)?;
+
     let mut res = TokenStream::new();
 
-    (min.unwrap_or(0)..=tuple_elements.len()).try_for_each(|i| {
+    arities.iter().try_for_each(|&i| {
         res.extend(ToTupleImplementation::generate_implementation(
             &trait_impl,
             &placeholder_ident.1,
             &tuple_elements[..i],
-            placeholder_ident.0,
+            &ElementAccess::Tuple,
+            &placeholder_ident.0,
         )?);
         Ok::<_, Error>(())
     })?;
 
+    if also_arrays {
+        if !matches!(placeholder_ident.0, TupleSelfType::Tuple) {
+            return Err(Error::new(
+                trait_impl.self_ty.span(),
+                "`also_arrays` requires the placeholder as self type, e.g. `impl Trait for Tuple`.",
+            ));
+        }
+
+        let element = crate::generate_tuple_element_ident(0);
+        let array = TupleSelfType::Array(element.clone());
+
+        arities.iter().try_for_each(|&i| {
+            // All elements of an array share the same type.
+            res.extend(ToTupleImplementation::generate_implementation(
+                &trait_impl,
+                &placeholder_ident.1,
+                &vec![element.clone(); i],
+                &ElementAccess::Array,
+                &array,
+            )?);
+            Ok::<_, Error>(())
+        })?;
+    }
+
     Ok(res)
 }
+
+/// Generate the semi-automatic tuple implementations for all trait implementations in `module`.
+///
+/// Only the implementations for the placeholder are expanded. The placeholder is taken from the
+/// first implementation whose self type is not declared or imported in the module. All other items
+/// of the module are kept as they are.
+pub fn semi_automatic_module_impl(
+    mut module: ItemMod,
+    tuple_elements: Vec<Ident>,
+    arities: &[usize],
+    also_arrays: bool,
+) -> Result<TokenStream> {
+    let Some((brace, items)) = module.content.take() else {
+        return Err(Error::new(
+            module.span(),
+            "Expected a module with a body, e.g. `mod tuple_impls { .. }`.",
+        ));
+    };
+
+    // Types declared in the module can not be the placeholder.
+    let mut declared = Vec::new();
+    items
+        .iter()
+        .for_each(|item| collect_declared_type_idents(item, &mut declared));
+
+    let placeholder_of = |trait_impl: &ItemImpl| {
+        extract_tuple_placeholder_ident(trait_impl)
+            .ok()
+            .map(|(_, placeholder)| placeholder)
+    };
+
+    // The first implementation for an undeclared type defines the placeholder.
+    let placeholder = items.iter().find_map(|item| match item {
+        Item::Impl(trait_impl) => {
+            placeholder_of(trait_impl).filter(|placeholder| !declared.contains(placeholder))
+        }
+        _ => None,
+    });
+
+    let mut content = TokenStream::new();
+    for item in items {
+        match item {
+            Item::Impl(trait_impl)
+                if placeholder.is_some() && placeholder_of(&trait_impl) == placeholder =>
+            {
+                content.extend(semi_automatic_impl(
+                    trait_impl,
+                    tuple_elements.clone(),
+                    arities,
+                    also_arrays,
+                )?)
+            }
+            item => item.to_tokens(&mut content),
+        }
+    }
+
+    let ItemMod {
+        attrs,
+        vis,
+        unsafety,
+        mod_token,
+        ident,
+        ..
+    } = module;
+    let mut res = quote!( #( #attrs )* #vis #unsafety #mod_token #ident );
+    brace.surround(&mut res, |tokens| tokens.extend(content));
+
+    Ok(res)
+}
+
+/// Collect the idents of the types declared or imported by `item`.
+fn collect_declared_type_idents(item: &Item, declared: &mut Vec<Ident>) {
+    fn collect_from_use_tree(tree: &UseTree, declared: &mut Vec<Ident>) {
+        match tree {
+            UseTree::Path(path) => collect_from_use_tree(&path.tree, declared),
+            UseTree::Name(name) => declared.push(name.ident.clone()),
+            UseTree::Rename(rename) => declared.push(rename.rename.clone()),
+            UseTree::Glob(_) => {}
+            UseTree::Group(group) => group
+                .items
+                .iter()
+                .for_each(|tree| collect_from_use_tree(tree, declared)),
+        }
+    }
+
+    match item {
+        Item::Enum(item) => declared.push(item.ident.clone()),
+        Item::Struct(item) => declared.push(item.ident.clone()),
+        Item::Trait(item) => declared.push(item.ident.clone()),
+        Item::Type(item) => declared.push(item.ident.clone()),
+        Item::Union(item) => declared.push(item.ident.clone()),
+        Item::Use(item) => collect_from_use_tree(&item.tree, declared),
+        _ => {}
+    }
+}
+
+/// Generate the semi-automatic implementation of `template` for a struct.
+///
+/// The fields of the struct are the tuple elements. `fields` are the types of the fields and
+/// `members` are used to access them.
+pub fn semi_automatic_fields_impl(
+    template: &ItemImpl,
+    self_ty: Type,
+    fields: &[Ident],
+    members: Vec<Member>,
+) -> Result<TokenStream> {
+    let (TupleSelfType::Tuple, placeholder_ident) = extract_tuple_placeholder_ident(template)?
+    else {
+        return Err(Error::new(
+            template.self_ty.span(),
+            "Expected the placeholder as self type, e.g. `impl Trait for Fields`.",
+        ));
+    };
+
+    TupleElementBounds::extract(&mut template.attrs.clone())?.check_overrides(&[fields.len()])?;
+
+    ToTupleImplementation::generate_implementation(
+        template,
+        &placeholder_ident,
+        fields,
+        &ElementAccess::Members(members),
+        &TupleSelfType::Fields(self_ty),
+    )
+}
@@ -0,0 +1,180 @@
+//! Implementation of the `tuple_items!` macro.
+//!
+//! The macro expands a template of arbitrary items once for each tuple arity in the given range.
+//! The template uses the same repetition syntax `#( Tuple )SEPARATOR*` as the semi-automatic mode.
+
+use crate::semi_automatic::{expand_repetitions_in_tokens, with_stream, ElementAccess};
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    token, Error, Ident, LitInt, Result,
+};
+
+use quote::{quote, ToTokens};
+
+/// The input of the `tuple_items!` macro.
+///
+/// `MIN..=MAX, |Tuple| { ITEMS }` or `MIN..MAX, |Tuple| { ITEMS }`
+pub struct TupleItems {
+    /// The smallest tuple arity to generate the items for.
+    min: usize,
+    /// The biggest tuple arity to generate the items for.
+    max: usize,
+    /// The tuple placeholder used in the items.
+    tuple_placeholder_ident: Ident,
+    /// The items with the tuple repetitions.
+    items: TokenStream,
+}
+
+impl Parse for TupleItems {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let min = input.parse::<LitInt>()?.base10_parse()?;
+        let lookahead1 = input.lookahead1();
+        let max = if lookahead1.peek(token::DotDotEq) {
+            input.parse::<token::DotDotEq>()?;
+            input.parse::<LitInt>()?.base10_parse()?
+        } else if lookahead1.peek(token::DotDot) {
+            input.parse::<token::DotDot>()?;
+            let end = input.parse::<LitInt>()?;
+            end.base10_parse::<usize>()?.checked_sub(1).ok_or_else(|| {
+                Error::new(end.span(), "The range needs to contain at least one arity!")
+            })?
+        } else {
+            return Err(lookahead1.error());
+        };
+
+        if min > max {
+            return Err(input.error("The range needs to contain at least one arity!"));
+        }
+
+        input.parse::<token::Comma>()?;
+        input.parse::<token::Or>()?;
+        let tuple_placeholder_ident = input.parse()?;
+        input.parse::<token::Or>()?;
+
+        let content;
+        braced!(content in input);
+
+        Ok(Self {
+            min,
+            max,
+            tuple_placeholder_ident,
+            items: content.parse()?,
+        })
+    }
+}
+
+/// Replace the tuple placeholder outside of repetitions in `tokens`.
+///
+/// - The placeholder is replaced by the tuple type, e.g. `(T0, T1,)`.
+/// - `#arity` is replaced by the number of tuple elements.
+/// - `#ident( .. )` joins the idents and integers in the parenthesis to one ident, e.g.
+///   `#ident(zip_ #arity)` becomes `zip_2`.
+fn replace_tuple_in_tokens(
+    tokens: TokenStream,
+    tuple_placeholder_ident: &Ident,
+    tuples: &[Ident],
+) -> Result<TokenStream> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut res = TokenStream::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (TokenTree::Ident(ident), _, _) if ident == tuple_placeholder_ident => {
+                res.extend(quote!( ( #( #tuples, )* ) ))
+            }
+            (TokenTree::Punct(pound), Some(TokenTree::Ident(ident)), _)
+                if pound.as_char() == '#' && ident == "arity" =>
+            {
+                res.extend(LitInt::new(&tuples.len().to_string(), ident.span()).to_token_stream());
+                i += 1;
+            }
+            (
+                TokenTree::Punct(pound),
+                Some(TokenTree::Ident(ident)),
+                Some(TokenTree::Group(group)),
+            ) if pound.as_char() == '#'
+                && ident == "ident"
+                && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let parts =
+                    replace_tuple_in_tokens(group.stream(), tuple_placeholder_ident, tuples)?;
+                res.extend(Some(TokenTree::Ident(join_ident(parts, group.span())?)));
+                i += 2;
+            }
+            (TokenTree::Group(group), _, _) => res.extend(Some(TokenTree::Group(with_stream(
+                group,
+                replace_tuple_in_tokens(group.stream(), tuple_placeholder_ident, tuples)?,
+            )))),
+            (token, _, _) => res.extend(Some(token.clone())),
+        }
+
+        i += 1;
+    }
+
+    Ok(res)
+}
+
+/// Join the idents and integers in `parts` to one ident, see [`replace_tuple_in_tokens`].
+///
+/// `span` is used for the joined ident and for the errors.
+fn join_ident(parts: TokenStream, span: Span) -> Result<Ident> {
+    let mut name = String::new();
+
+    for part in parts {
+        match part {
+            TokenTree::Ident(ident) => name.push_str(&ident.to_string()),
+            TokenTree::Literal(lit) if syn::parse2::<LitInt>(lit.to_token_stream()).is_ok() => {
+                name.push_str(&lit.to_string())
+            }
+            part => {
+                return Err(Error::new(
+                    part.span(),
+                    "Expected idents, integers or `#arity` in `#ident( .. )`.",
+                ))
+            }
+        }
+    }
+
+    syn::parse_str::<Ident>(&name)
+        .map(|ident| Ident::new(&ident.to_string(), span))
+        .map_err(|_| {
+            Error::new(
+                span,
+                format!(
+                    "`#ident( .. )` needs to form a valid identifier, found `{}`.",
+                    name
+                ),
+            )
+        })
+}
+
+/// Generate the items for all tuple arities given in `input`.
+pub fn tuple_items_impl(input: TupleItems) -> Result<TokenStream> {
+    let tuple_elements = (0..input.max)
+        .map(crate::generate_tuple_element_ident)
+        .collect::<Vec<_>>();
+    let mut res = TokenStream::new();
+
+    for arity in input.min..=input.max {
+        let tuples = &tuple_elements[..arity];
+        let items = expand_repetitions_in_tokens(
+            input.items.clone(),
+            &input.tuple_placeholder_ident,
+            tuples,
+            &ElementAccess::Tuple,
+        )?;
+
+        res.extend(replace_tuple_in_tokens(
+            items,
+            &input.tuple_placeholder_ident,
+            tuples,
+        )?);
+    }
+
+    Ok(res)
+}
@@ -2,10 +2,24 @@
 
 use proc_macro2::TokenStream;
 
-use syn::{parse_quote, Generics, Ident};
+use syn::{parse_quote, Error, Generics, Ident, Result, TypeParamBound};
 
 use quote::quote;
 
+/// The maximum number of tuple elements for that the standard library implements its traits.
+pub const STD_TUPLE_MAX_ELEMENTS: usize = 12;
+
+/// The traits that are only implemented for tuples up to [`STD_TUPLE_MAX_ELEMENTS`] elements.
+const STD_TUPLE_TRAITS: &[&str] = &[
+    "Debug",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+];
+
 /// Add the given tuple elements as generics with the given `bounds` to `generics`.
 pub fn add_tuple_element_generics(
     tuple_elements: &[Ident],
@@ -18,3 +32,53 @@ pub fn add_tuple_element_generics(
         generics.params.push(parse_quote!(#tuple_element #bound));
     });
 }
+
+/// Checks that none of the `bounds` requires a trait of the standard library that is not implemented
+/// for tuples with `max` elements.
+pub fn check_std_tuple_bounds<'a>(
+    bounds: impl IntoIterator<Item = &'a TypeParamBound>,
+    max: usize,
+) -> Result<()> {
+    if max <= STD_TUPLE_MAX_ELEMENTS {
+        return Ok(());
+    }
+
+    let mut errors = bounds.into_iter().filter_map(|bound| {
+        let TypeParamBound::Trait(bound) = bound else {
+            return None;
+        };
+        // Only a bare ident or a path into the standard library can refer to a std trait, a path
+        // like `crate::Hash` refers to a trait with the same name.
+        let segments = &bound.path.segments;
+        let is_std_path = segments.len() == 1
+            || ["core", "std", "alloc"]
+                .iter()
+                .any(|c| segments[0].ident == c);
+        if !is_std_path {
+            return None;
+        }
+        let ident = &segments.last()?.ident;
+
+        STD_TUPLE_TRAITS.iter().any(|t| ident == t).then(|| {
+            Error::new(
+                ident.span(),
+                format!(
+                    "`{}` is only implemented by the standard library for tuples with up to {} \
+                     elements, but implementations for tuples with {} elements are requested. \
+                     Reduce the number of elements or use `nested` to support more elements by \
+                     nesting tuples, e.g. `((A, .., L), (M, .., X))`. A trait with the same name \
+                     needs to be referenced by its path, e.g. `crate::{}`.",
+                    ident, STD_TUPLE_MAX_ELEMENTS, max, ident,
+                ),
+            )
+        })
+    });
+
+    match errors.next() {
+        Some(first) => Err(errors.fold(first, |mut e, n| {
+            e.combine(n);
+            e
+        })),
+        None => Ok(()),
+    }
+}
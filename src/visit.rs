@@ -0,0 +1,98 @@
+//! Implementation of the `tuple_visit` attribute.
+//!
+//! The attribute generates a visitor trait for the annotated bound trait and a trait that is
+//! implemented for tuples, to call the visitor for each tuple element. The tuple implementations
+//! are generated by the semi-automatic mode.
+
+use crate::semi_automatic::semi_automatic_impl;
+
+use proc_macro2::{Punct, Spacing, TokenStream};
+
+use syn::{parse_quote, spanned::Spanned, Error, Ident, ItemImpl, ItemTrait, Result};
+
+use quote::{format_ident, quote};
+
+/// Generate the visitor traits for the bound trait `definition` and implement them for the tuples.
+pub fn tuple_visit_impl(
+    definition: ItemTrait,
+    tuple_elements: Vec<Ident>,
+    arities: &[usize],
+) -> Result<TokenStream> {
+    if !definition.generics.params.is_empty() {
+        return Err(Error::new(
+            definition.generics.span(),
+            "Generic traits are not supported by `tuple_visit`.",
+        ));
+    }
+
+    let vis = &definition.vis;
+    let bound = &definition.ident;
+    let visitor = format_ident!("{}Visitor", bound);
+    let visit = format_ident!("{}Visit", bound);
+    let visitor_doc = format!(
+        "A visitor that is called with each element of a tuple that implements [`{}`].",
+        visit,
+    );
+    let visit_doc = format!(
+        "Calls a [`{}`] for each element of a tuple, starting with the first element.",
+        visitor,
+    );
+
+    // `#` can not be written directly in `quote!`, as it starts an interpolation.
+    let pound = Punct::new('#', Spacing::Alone);
+    let template: ItemImpl = parse_quote! {
+        #[tuple_types_custom_trait_bound(#bound)]
+        impl #visit for Tuple {
+            fn visit_each<V: #visitor>(&self, visitor: &mut V) {
+                for_tuples!( #pound( visitor.visit(&self.Tuple); )* );
+            }
+
+            fn visit_each_mut<V: #visitor>(&mut self, visitor: &mut V) {
+                for_tuples!( #pound( visitor.visit_mut(&mut self.Tuple); )* );
+            }
+
+            fn visit_each_owned<V: #visitor>(self, visitor: &mut V) {
+                for_tuples!( #pound( visitor.visit_owned(self.Tuple); )* );
+            }
+        }
+    };
+    let impls = semi_automatic_impl(template, tuple_elements, arities, false)?;
+
+    Ok(quote! {
+        #definition
+
+        #[doc = #visitor_doc]
+        #vis trait #visitor {
+            /// Visit the given element.
+            fn visit<T: #bound>(&mut self, element: &T);
+
+            /// Visit the given mutable element.
+            ///
+            /// Calls [`Self::visit`] by default.
+            fn visit_mut<T: #bound>(&mut self, element: &mut T) {
+                self.visit(&*element)
+            }
+
+            /// Visit the given owned element.
+            ///
+            /// Calls [`Self::visit`] by default.
+            fn visit_owned<T: #bound>(&mut self, element: T) {
+                self.visit(&element)
+            }
+        }
+
+        #[doc = #visit_doc]
+        #vis trait #visit {
+            /// Call `visitor` with a reference to each element.
+            fn visit_each<V: #visitor>(&self, visitor: &mut V);
+
+            /// Call `visitor` with a mutable reference to each element.
+            fn visit_each_mut<V: #visitor>(&mut self, visitor: &mut V);
+
+            /// Call `visitor` with each element.
+            fn visit_each_owned<V: #visitor>(self, visitor: &mut V);
+        }
+
+        #impls
+    })
+}
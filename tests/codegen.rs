@@ -1,4 +1,4 @@
-use impl_trait_for_tuples::impl_for_tuples;
+use impl_trait_for_tuples::{derive_fieldwise, impl_for_tuples, nested, tuple_items, tuple_visit};
 
 #[test]
 fn is_implemented_for_tuples() {
@@ -49,6 +49,9 @@ fn full_automatic_accept_empty_tuple_return() {
 
 #[test]
 fn full_automatic_unsafe_trait() {
+    /// # Safety
+    ///
+    /// Nothing to uphold, the trait only checks that `unsafe` is kept.
     #[impl_for_tuples(5)]
     unsafe trait UnsafeTrait {
         fn test();
@@ -289,6 +292,7 @@ fn trait_with_associated_type() {
     }
 
     let mut counter = 0;
+    #[allow(clippy::let_unit_value)]
     let res = test::<()>(&mut counter);
     assert_eq!(0, counter);
     assert_eq!((), res);
@@ -339,6 +343,7 @@ fn trait_with_associated_type_and_generics() {
     }
 
     let mut counter = 0;
+    #[allow(clippy::let_unit_value)]
     let res = test::<()>(&mut counter);
     assert_eq!(0, counter);
     assert_eq!((), res);
@@ -389,6 +394,7 @@ fn trait_with_associated_type_and_self() {
     }
 
     let mut counter = 0;
+    #[allow(clippy::let_unit_value)]
     let res = test((), &mut counter);
     assert_eq!(0, counter);
     assert_eq!((), res);
@@ -445,6 +451,297 @@ fn semi_automatic_with_custom_where_clause() {
     }
 }
 
+#[test]
+fn semi_automatic_with_multiple_custom_where_clauses() {
+    trait Foo<T> {}
+
+    trait Trait {
+        type Arg;
+        type Bar;
+
+        fn test(arg: Self::Arg) -> u32;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        for_tuples!( where Self: Sized, #( Tuple: Foo<Tuple::Bar> )* );
+        for_tuples!( where #( Tuple: Trait<Arg=u32> ),* );
+        type Arg = u32;
+        type Bar = ();
+
+        fn test(arg: Self::Arg) -> u32 {
+            let mut res = 0;
+            for_tuples!( #( res += Tuple::test(arg); )* );
+            res
+        }
+    }
+
+    struct Impl;
+
+    impl Foo<u32> for Impl {}
+
+    impl Trait for Impl {
+        type Arg = u32;
+        type Bar = u32;
+
+        fn test(arg: Self::Arg) -> u32 {
+            arg
+        }
+    }
+
+    assert_eq!(6, <(Impl, Impl, Impl)>::test(2));
+}
+
+#[test]
+fn semi_automatic_repetitions_in_method_signatures() {
+    trait Trait {
+        type Ret;
+
+        fn convert<T: Into<u32> + Copy>(t: T) -> Self::Ret;
+        fn convert_ref(arg: &Self::Ret) -> Self::Ret;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        for_tuples!( type Ret = ( #( Tuple::Ret ),* ); );
+
+        #[for_tuples(where #( Tuple: Trait ),*)]
+        fn convert<T: Into<u32> + Copy>(t: T) -> for_tuples!( ( #( Tuple::Ret ),* ) ) {
+            for_tuples!( ( #( Tuple::convert(t) ),* ) )
+        }
+
+        fn convert_ref(arg: &for_tuples!( ( #( Tuple::Ret ),* ) )) -> Self::Ret {
+            for_tuples!( ( #( Tuple::convert_ref(&arg.Tuple) ),* ) )
+        }
+    }
+
+    struct Impl;
+
+    impl Trait for Impl {
+        type Ret = u32;
+
+        fn convert<T: Into<u32> + Copy>(t: T) -> u32 {
+            t.into()
+        }
+
+        fn convert_ref(arg: &u32) -> u32 {
+            *arg + 1
+        }
+    }
+
+    assert_eq!((2, 2, 2), <(Impl, Impl, Impl)>::convert(2u8));
+    assert_eq!((2, 3, 4), <(Impl, Impl, Impl)>::convert_ref(&(1, 2, 3)));
+}
+
+#[test]
+fn semi_automatic_repetitions_in_method_generics() {
+    trait Trait {
+        fn value(&self) -> u32;
+    }
+
+    impl Trait for u8 {
+        fn value(&self) -> u32 {
+            *self as u32
+        }
+    }
+
+    struct Values<T>(T);
+
+    // Not a declared identifier, so it is kept as it is.
+    type Tuple2 = u32;
+
+    #[impl_for_tuples(5)]
+    impl Values<Tuple> {
+        #[for_tuples(<#( Tuple_T: From<Tuple> ),*>)]
+        fn convert(self) -> for_tuples!( ( #( Tuple_T ),* ) ) {
+            for_tuples!( ( #( Tuple_T::from(self.0.Tuple) ),* ) )
+        }
+
+        #[for_tuples(where #( Tuple: Trait )*)]
+        fn sum(&self) -> u32 {
+            let mut sum = 0;
+            for_tuples!( #( sum += self.0.Tuple.value() as Tuple2; )* );
+            sum
+        }
+    }
+
+    assert_eq!((1u16, 2u32), Values((1u8, 2u16)).convert::<u16, u32>());
+    let () = Values(()).convert();
+    assert_eq!(6, Values((1u8, 2u8, 3u8)).sum());
+    assert_eq!(0, Values(()).sum());
+}
+
+#[test]
+fn semi_automatic_zip_self_with_argument() {
+    trait Trait {
+        type State;
+
+        fn run(&self, state: &mut Self::State);
+        fn run_nested(&self, args: (u32, &mut Wrapper<Self::State>));
+        fn run_ptr(&self, state: *mut Self::State);
+        fn ids(&self, ids: &mut [u32]);
+    }
+
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        for_tuples!( type State = ( #( Tuple::State ),* ); );
+
+        fn run(&self, state: &mut Self::State) {
+            for_tuples!( #( Tuple.run(&mut state.Tuple); )* );
+        }
+
+        fn run_nested(&self, args: (u32, &mut Wrapper<Self::State>)) {
+            for_tuples!( #( self.Tuple.run(&mut args.1.inner.Tuple); )* );
+        }
+
+        fn run_ptr(&self, state: *mut Self::State) {
+            for_tuples!( #( self.Tuple.run(unsafe { &mut (*state).Tuple }); )* );
+        }
+
+        fn ids(&self, ids: &mut [u32]) {
+            for_tuples!( #( Tuple.ids(&mut ids[#index..]); )* );
+        }
+    }
+
+    struct Impl(u32);
+
+    impl Trait for Impl {
+        type State = u32;
+
+        fn run(&self, state: &mut u32) {
+            *state += self.0;
+        }
+
+        fn run_nested(&self, args: (u32, &mut Wrapper<u32>)) {
+            args.1.inner += args.0;
+        }
+
+        fn run_ptr(&self, _: *mut u32) {}
+
+        fn ids(&self, ids: &mut [u32]) {
+            ids[0] = self.0;
+        }
+    }
+
+    let tuple = (Impl(1), Impl(2), Impl(3));
+
+    let mut state = (10, 20, 30);
+    tuple.run(&mut state);
+    assert_eq!((11, 22, 33), state);
+
+    let mut wrapper = Wrapper { inner: state };
+    tuple.run_nested((0, &mut wrapper));
+    assert_eq!((12, 24, 36), wrapper.inner);
+
+    tuple.run_ptr(&mut state);
+    assert_eq!((12, 24, 36), state);
+
+    let mut ids = [0; 3];
+    tuple.ids(&mut ids);
+    assert_eq!([1, 2, 3], ids);
+}
+
+#[test]
+fn semi_automatic_destructuring_patterns() {
+    trait Trait {
+        fn consume(self) -> u32;
+        fn count(&self) -> u32;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        fn consume(self) -> u32 {
+            let for_tuples!( ( #( Tuple ),* ) ) = self;
+            let mut res = 0;
+            for_tuples!( #( res += Trait::consume(Tuple); )* );
+            res
+        }
+
+        fn count(&self) -> u32 {
+            match Some(self) {
+                Some(for_tuples!( ( #( Tuple_ref ),* ) )) => {
+                    let mut res = 0;
+                    for_tuples!( #( res += Tuple_ref.count(); )* );
+                    res
+                }
+                None => 0,
+            }
+        }
+    }
+
+    struct Impl(u32);
+
+    impl Trait for Impl {
+        fn consume(self) -> u32 {
+            self.0
+        }
+
+        fn count(&self) -> u32 {
+            self.0
+        }
+    }
+
+    assert_eq!(6, (Impl(1), Impl(2), Impl(3)).count());
+    assert_eq!(6, (Impl(1), Impl(2), Impl(3)).consume());
+    assert_eq!(4, (Impl(4),).count());
+    assert_eq!(4, (Impl(4),).consume());
+    assert_eq!(0, ().consume());
+}
+
+#[test]
+fn semi_automatic_per_element_locals() {
+    trait Trait {
+        type Output;
+
+        fn start(&self) -> u32;
+        fn finish(&self, started: u32) -> Self::Output;
+        fn run(&self) -> Self::Output;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        for_tuples!( type Output = ( #( Tuple::Output ),* ); );
+
+        fn start(&self) -> u32 {
+            0
+        }
+
+        fn finish(&self, _: u32) -> Self::Output {
+            self.run()
+        }
+
+        fn run(&self) -> Self::Output {
+            for_tuples!( #( let Tuple_started = Tuple.start(); )* );
+            for_tuples!( #( let Tuple2 = Tuple.finish(Tuple_started); )* );
+            for_tuples!( ( #( Tuple2 ),* ) )
+        }
+    }
+
+    struct Impl(u32);
+
+    impl Trait for Impl {
+        type Output = u32;
+
+        fn start(&self) -> u32 {
+            self.0 * 10
+        }
+
+        fn finish(&self, started: u32) -> u32 {
+            started + self.0
+        }
+
+        fn run(&self) -> u32 {
+            self.finish(self.start())
+        }
+    }
+
+    assert_eq!((11, 22, 33), (Impl(1), Impl(2), Impl(3)).run());
+}
+
 #[test]
 fn for_tuples_in_nested_expr_works() {
     trait Trait {
@@ -568,6 +865,142 @@ fn semi_automatic_tuple_with_custom_advanced_trait_bound() {
     }
 }
 
+#[test]
+fn semi_automatic_tuple_with_per_element_trait_bounds() {
+    trait Describe {
+        fn describe(&self) -> String;
+    }
+
+    // For 1-tuples `0` and `last` would point to the same element.
+    #[impl_for_tuples(2, 5)]
+    #[tuple_types_custom_trait_bound(0: Describe + Default, last: ?Sized + Describe)]
+    impl Describe for Tuple {
+        fn describe(&self) -> String {
+            let mut res = String::new();
+            for_tuples!( #( res.push_str(&self.Tuple.describe()); )* );
+            res
+        }
+    }
+
+    impl Describe for u32 {
+        fn describe(&self) -> String {
+            self.to_string()
+        }
+    }
+
+    impl Describe for [u8] {
+        fn describe(&self) -> String {
+            format!("{:?}", self)
+        }
+    }
+
+    fn assert_describe<T: Describe + ?Sized>() {}
+    assert_describe::<(u32, [u8])>();
+    assert_describe::<(u32, u32, [u8])>();
+
+    assert_eq!("123", (1u32, 2u32, 3u32).describe());
+}
+
+#[test]
+fn semi_automatic_tuple_with_overrides_and_no_default_trait_bound() {
+    trait Count {
+        fn count() -> usize;
+    }
+
+    #[impl_for_tuples(3)]
+    #[tuple_types_no_default_trait_bound]
+    #[tuple_types_custom_trait_bound(last: ?Sized)]
+    impl Count for Tuple {
+        fn count() -> usize {
+            let mut count = 0;
+            for_tuples!( #( count += 1; )* );
+            count
+        }
+    }
+
+    // Only the last element gets a bound, the other elements don't need to implement `Count`.
+    struct NoCount;
+
+    assert_eq!(0, <()>::count());
+    assert_eq!(1, <(NoCount,)>::count());
+    assert_eq!(2, <(NoCount, str)>::count());
+    assert_eq!(3, <(NoCount, NoCount, [u8])>::count());
+}
+
+#[test]
+fn semi_automatic_tuple_with_extra_trait_bounds() {
+    trait Spawn {
+        fn spawn(self) -> Vec<std::thread::JoinHandle<()>>;
+    }
+
+    trait Job {
+        fn run(self);
+    }
+
+    #[impl_for_tuples(5)]
+    #[tuple_types_custom_trait_bound(Job)]
+    #[tuple_types_extra_trait_bound(Send)]
+    #[tuple_types_extra_trait_bound('static)]
+    impl Spawn for Tuple {
+        fn spawn(self) -> Vec<std::thread::JoinHandle<()>> {
+            let mut handles = Vec::new();
+            for_tuples!( #( let job = self.Tuple; handles.push(std::thread::spawn(move || job.run())); )* );
+            handles
+        }
+    }
+
+    struct Set(std::sync::Arc<std::sync::atomic::AtomicU32>);
+
+    impl Job for Set {
+        fn run(self) {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    let counter = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+    (Set(counter.clone()), Set(counter.clone()))
+        .spawn()
+        .into_iter()
+        .for_each(|h| h.join().unwrap());
+    assert_eq!(2, counter.load(std::sync::atomic::Ordering::Relaxed));
+}
+
+#[test]
+fn semi_automatic_inherent_impl() {
+    #[derive(Debug, PartialEq)]
+    struct Values<T>(T);
+
+    #[impl_for_tuples(5)]
+    impl Values<Tuple> {
+        #[for_tuples(<#( Tuple_V: Into<Tuple> ),*>)]
+        fn from_values(values: for_tuples!( ( #( Tuple_V ),* ) )) -> Self {
+            Values(for_tuples!( ( #( values.Tuple.into() ),* ) ))
+        }
+
+        fn len(&self) -> usize {
+            let mut len = 0;
+            for_tuples!( #( let _ = &self.0.Tuple; len += 1; )* );
+            len
+        }
+    }
+
+    #[impl_for_tuples(5)]
+    #[tuple_types_custom_trait_bound(Default)]
+    impl Values<Tuple> {
+        fn new() -> Self {
+            Values(for_tuples!( ( #( Tuple::default() ),* ) ))
+        }
+    }
+
+    assert_eq!(
+        Values((1u32, 2u64)),
+        Values::<(u32, u64)>::from_values((1u8, 2u16))
+    );
+    assert_eq!(3, Values((1, 2, 3)).len());
+    assert_eq!(0, Values(()).len());
+    assert_eq!(Values((0u32,)), Values::<(u32,)>::new());
+}
+
 #[test]
 fn semi_automatic_tuple_as_ref() {
     trait Trait {
@@ -586,62 +1019,465 @@ fn semi_automatic_tuple_as_ref() {
 }
 
 #[test]
-fn semi_automatic_associated_const() {
-    trait Trait {
-        const TYPE: &'static [u32];
-    }
+fn semi_automatic_tuple_as_mut_ref_with_lifetime() {
+    trait Reset {
+        type Output;
 
-    trait Trait2 {
-        const TYPE: u32;
+        fn reset(self) -> Self::Output;
     }
 
-    #[impl_for_tuples(1, 5)]
+    #[impl_for_tuples(5)]
     #[tuple_types_no_default_trait_bound]
-    impl Trait for Tuple {
-        for_tuples!( where #( Tuple: Trait2 )* );
+    impl<'a> Reset for &'a mut Tuple {
+        for_tuples!( where #( Tuple: Default )* );
+        for_tuples!( type Output = ( #( &'a Tuple ),* ); );
 
-        for_tuples!( const TYPE: &'static [u32] = &[ #( Tuple::TYPE ),* ]; );
+        fn reset(self) -> Self::Output {
+            for_tuples!( #( *self.Tuple = Tuple::default(); )* );
+            for_tuples!( ( #( &*self.Tuple ),* ) )
+        }
     }
+
+    let mut a = 5u32;
+    let mut b = String::from("test");
+    let (a, b) = (&mut a, &mut b).reset();
+    assert_eq!((&0, &String::new()), (a, b));
 }
 
 #[test]
-fn semi_automatic_associated_const_calculation() {
-    trait Trait {
-        const TYPE: u32;
+fn semi_automatic_wrapper_self_types() {
+    trait Count {
+        fn count(&self) -> u32;
     }
 
-    #[impl_for_tuples(1, 5)]
-    impl Trait for Tuple {
-        for_tuples!( const TYPE: u32 = #( Tuple::TYPE )+*; );
-    }
+    struct Wrapper<T>(T);
 
-    struct Test;
-    impl Trait for Test {
-        const TYPE: u32 = 1;
+    #[impl_for_tuples(5)]
+    impl Count for Wrapper<Tuple> {
+        fn count(&self) -> u32 {
+            let mut count = 0;
+            for_tuples!( #( count += self.0.Tuple.count(); )* );
+            count
+        }
     }
 
-    assert_eq!(3, <(Test, Test, Test)>::TYPE);
-    assert_eq!(1, Test::TYPE);
-}
-
-#[test]
-fn semi_automatic_associated_type() {
-    trait Trait {
-        type A;
-        type B;
+    #[impl_for_tuples(5)]
+    impl Count for Box<Tuple> {
+        fn count(&self) -> u32 {
+            let tuple = &**self;
+            let mut count = 0;
+            for_tuples!( #( count += tuple.Tuple.count(); )* );
+            count
+        }
     }
 
     #[impl_for_tuples(5)]
-    impl Trait for Tuple {
-        for_tuples!( type A = ( #( Option< Tuple::B > ),* ); );
-        for_tuples!( type B = ( #( Tuple::B ),* ); );
+    impl Count for [Tuple; 2] {
+        fn count(&self) -> u32 {
+            let mut count = 0;
+            for_tuples!( #( count += self[0].Tuple.count() + self[1].Tuple.count(); )* );
+            count
+        }
+    }
+
+    impl Count for u32 {
+        fn count(&self) -> u32 {
+            *self
+        }
     }
+
+    assert_eq!(3, Wrapper((1u32, 2u32)).count());
+    assert_eq!(6, Box::new((1u32, 2u32, 3u32)).count());
+    assert_eq!(10, [(1u32, 2u32), (3, 4)].count());
+    assert_eq!(0, Wrapper(()).count());
 }
 
 #[test]
-fn semi_automatic_unsafe_trait() {
-    unsafe trait Trait {
-        type A;
+fn semi_automatic_wrapper_self_types_with_other_type_arguments() {
+    trait Count {
+        fn count(&self) -> u32;
+    }
+
+    impl Count for u32 {
+        fn count(&self) -> u32 {
+            *self
+        }
+    }
+
+    struct Pair<A, B>(A, B);
+
+    #[impl_for_tuples(3)]
+    impl Count for Pair<u32, Elements> {
+        fn count(&self) -> u32 {
+            let mut count = self.0;
+            for_tuples!( #( count += self.1.Elements.count(); )* );
+            count
+        }
+    }
+
+    #[impl_for_tuples(3)]
+    impl Count for Pair<std::string::String, Tuple> {
+        fn count(&self) -> u32 {
+            let mut count = self.0.len() as u32;
+            for_tuples!( #( count += self.1.Tuple.count(); )* );
+            count
+        }
+    }
+
+    assert_eq!(6, Pair(1, (2u32, 3u32)).count());
+    assert_eq!(5, Pair(String::from("ab"), (1u32, 2u32)).count());
+}
+
+#[test]
+fn semi_automatic_element_wrappers_in_self_type() {
+    trait Handler {
+        fn handle(&self, value: u32) -> u32;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Handler for for_tuples!( ( #( Option<Tuple> ),* ) ) {
+        fn handle(&self, value: u32) -> u32 {
+            let mut value = value;
+            for_tuples!( #( if let Some(handler) = &self.Tuple { value = handler.handle(value); } )* );
+            value
+        }
+    }
+
+    struct Add(u32);
+
+    impl Handler for Add {
+        fn handle(&self, value: u32) -> u32 {
+            value + self.0
+        }
+    }
+
+    assert_eq!(5, (Some(Add(1)), None::<Add>, Some(Add(3))).handle(1));
+    assert_eq!(1, (None::<Add>,).handle(1));
+    assert_eq!(1, ().handle(1));
+}
+
+#[test]
+fn semi_automatic_associated_const() {
+    #[allow(dead_code)]
+    trait Trait {
+        const TYPE: &'static [u32];
+    }
+
+    trait Trait2 {
+        const TYPE: u32;
+    }
+
+    #[impl_for_tuples(1, 5)]
+    #[tuple_types_no_default_trait_bound]
+    impl Trait for Tuple {
+        for_tuples!( where #( Tuple: Trait2 )* );
+
+        for_tuples!( const TYPE: &'static [u32] = &[ #( Tuple::TYPE ),* ]; );
+    }
+}
+
+#[test]
+fn semi_automatic_associated_const_calculation() {
+    trait Trait {
+        const TYPE: u32;
+    }
+
+    #[impl_for_tuples(1, 5)]
+    impl Trait for Tuple {
+        for_tuples!( const TYPE: u32 = #( Tuple::TYPE )+*; );
+    }
+
+    struct Test;
+    impl Trait for Test {
+        const TYPE: u32 = 1;
+    }
+
+    assert_eq!(3, <(Test, Test, Test)>::TYPE);
+    assert_eq!(1, Test::TYPE);
+}
+
+#[test]
+fn semi_automatic_associated_const_with_star_after_repetition() {
+    trait Trait {
+        const SIZE: u32;
+        const SCALED: u32;
+        const PRODUCT: u32;
+    }
+
+    #[impl_for_tuples(1, 3)]
+    impl Trait for Tuple {
+        for_tuples!( const SIZE: u32 = 0 #( + Tuple::SIZE )*; );
+        // `* 10` is a multiplication after the repetition.
+        for_tuples!( const SCALED: u32 = 1 #( + Tuple::SIZE )* * 10; );
+        // `**` uses `*` as separator.
+        for_tuples!( const PRODUCT: u32 = #( Tuple::SIZE )**; );
+    }
+
+    struct Two;
+    impl Trait for Two {
+        const SIZE: u32 = 2;
+        const SCALED: u32 = 2;
+        const PRODUCT: u32 = 2;
+    }
+
+    struct Three;
+    impl Trait for Three {
+        const SIZE: u32 = 3;
+        const SCALED: u32 = 3;
+        const PRODUCT: u32 = 3;
+    }
+
+    assert_eq!(33, <(Two, Three)>::SCALED);
+    assert_eq!(6, <(Two, Three)>::PRODUCT);
+    assert_eq!(21, <(Two,)>::SCALED);
+}
+
+#[test]
+fn semi_automatic_associated_const_with_expressions() {
+    const fn max_of(values: &[usize]) -> usize {
+        let mut max = 0;
+        let mut i = 0;
+        while i < values.len() {
+            if values[i] > max {
+                max = values[i];
+            }
+            i += 1;
+        }
+        max
+    }
+
+    trait Trait {
+        const SIZE: usize;
+        const MAX: usize;
+        const ID: u32;
+        type Ids;
+        const IDS: Self::Ids;
+        const FLAGS: u32;
+        const SCALED: u32;
+        const SUM: usize;
+    }
+
+    #[impl_for_tuples(1, 3)]
+    impl Trait for Tuple {
+        for_tuples!( const SIZE: usize = 0 #( + Tuple::SIZE )*; );
+        for_tuples!( const MAX: usize = max_of(&[ #( Tuple::SIZE ),* ]); );
+        const ID: u32 = 0;
+        for_tuples!( type Ids = [u32; #( 1 )+*]; );
+        for_tuples!( const IDS: Self::Ids = [ #( Tuple::ID ),* ]; );
+        for_tuples!( const FLAGS: u32 = { let mut x = 0; #( x |= Tuple::FLAGS; )* x }; );
+        for_tuples!( const SCALED: u32 = #( Tuple::SCALED * 2 + 1 )+*; );
+        const SUM: usize = max_of(&[for_tuples!( #( Tuple::SUM ),* )]);
+    }
+
+    struct A;
+    impl Trait for A {
+        const SIZE: usize = 1;
+        const MAX: usize = 1;
+        const ID: u32 = 1;
+        type Ids = ();
+        const IDS: () = ();
+        const FLAGS: u32 = 0b001;
+        const SCALED: u32 = 1;
+        const SUM: usize = 1;
+    }
+
+    struct B;
+    impl Trait for B {
+        const SIZE: usize = 5;
+        const MAX: usize = 5;
+        const ID: u32 = 2;
+        type Ids = ();
+        const IDS: () = ();
+        const FLAGS: u32 = 0b100;
+        const SCALED: u32 = 2;
+        const SUM: usize = 5;
+    }
+
+    type T = (A, B, A);
+    assert_eq!(7, <T as Trait>::SIZE);
+    assert_eq!(5, <T as Trait>::MAX);
+    assert_eq!([1, 2, 1], <T as Trait>::IDS);
+    assert_eq!(0b101, <T as Trait>::FLAGS);
+    assert_eq!(11, <T as Trait>::SCALED);
+    assert_eq!(5, <T as Trait>::SUM);
+}
+
+#[test]
+fn semi_automatic_associated_type() {
+    trait Trait {
+        type A;
+        type B;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Trait for Tuple {
+        for_tuples!( type A = ( #( Option< Tuple::B > ),* ); );
+        for_tuples!( type B = ( #( Tuple::B ),* ); );
+    }
+}
+
+#[test]
+fn semi_automatic_associated_type_without_parenthesis() {
+    struct Chain<A, B>(A, B);
+
+    trait Trait {
+        const LEN: usize;
+        type Chained;
+        type Bytes;
+        type Options;
+    }
+
+    #[impl_for_tuples(1, 5)]
+    impl Trait for Tuple {
+        for_tuples!( const LEN: usize = #( Tuple::LEN )+*; );
+        for_tuples!( type Chained = #( Chain<Tuple, )* () #( > )*; );
+        for_tuples!( type Bytes = [u8; #( 1 )+*]; );
+        for_tuples!( type Options = Option<( #( Option<Tuple::Options> ),* )>; );
+    }
+
+    struct Impl;
+
+    impl Trait for Impl {
+        const LEN: usize = 2;
+        type Chained = Self;
+        type Bytes = [u8; 2];
+        type Options = ();
+    }
+
+    let _: <(Impl, Impl) as Trait>::Chained = Chain(Impl, Chain(Impl, ()));
+    let _: <(Impl, Impl, Impl) as Trait>::Bytes = [0u8; 3];
+    let _: <(Impl, Impl) as Trait>::Options = Some((Some(()), None));
+}
+
+#[test]
+fn semi_automatic_generic_associated_type() {
+    trait Lending {
+        type Item<'a>
+        where
+            Self: 'a;
+        type Mapped<T: Clone>: Clone;
+
+        fn get(&self) -> Self::Item<'_>;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Lending for Tuple {
+        for_tuples!( type Item<'a> = ( #( Tuple::Item<'a> ),* ) where Self: 'a; );
+        for_tuples!( type Mapped<T: Clone> = ( #( Tuple::Mapped<T> ),* ); );
+
+        fn get(&self) -> Self::Item<'_> {
+            for_tuples!( ( #( Tuple.get() ),* ) )
+        }
+    }
+
+    struct Impl(u32);
+
+    impl Lending for Impl {
+        type Item<'a> = &'a u32;
+        type Mapped<T: Clone> = Option<T>;
+
+        fn get(&self) -> &u32 {
+            &self.0
+        }
+    }
+
+    let tuple = (Impl(1), Impl(2));
+    assert_eq!((&1, &2), tuple.get());
+
+    let _: <(Impl, Impl) as Lending>::Mapped<u8> = (Some(1), None);
+}
+
+#[test]
+fn semi_automatic_generic_associated_type_with_leading_where_clause() {
+    trait Lending {
+        type Item<'a>
+        where
+            Self: 'a;
+        type Pair<'a>
+        where
+            Self: 'a;
+
+        fn get(&self) -> Self::Item<'_>;
+    }
+
+    #[impl_for_tuples(5)]
+    impl Lending for Tuple {
+        for_tuples!( type Item<'a> where Self: 'a = ( #( Tuple::Item<'a> ),* ); );
+        for_tuples!(
+            type Pair<'a>
+            where
+                Self: 'a,
+            = (Self::Item<'a>, Self::Item<'a>)
+            where
+                Self: 'a;
+        );
+
+        fn get(&self) -> Self::Item<'_> {
+            for_tuples!( ( #( Tuple.get() ),* ) )
+        }
+    }
+
+    struct Impl(u32);
+
+    impl Lending for Impl {
+        type Item<'a> = &'a u32;
+        type Pair<'a> = (&'a u32, &'a u32);
+
+        fn get(&self) -> &u32 {
+            &self.0
+        }
+    }
+
+    let tuple = (Impl(1), Impl(2));
+    assert_eq!((&1, &2), tuple.get());
+
+    let _: <(Impl, Impl) as Lending>::Pair<'_> = (tuple.get(), tuple.get());
+}
+
+#[test]
+fn semi_automatic_construct_self() {
+    trait Construct {
+        fn new(value: u32) -> Self;
+        fn new_parenthesized(value: u32) -> Self;
+    }
+
+    #[impl_for_tuples(3)]
+    impl Construct for Tuple {
+        fn new(value: u32) -> Self {
+            for_tuples!( Self( #( Tuple::new(value) )* ) )
+        }
+
+        fn new_parenthesized(value: u32) -> Self {
+            for_tuples!( ( #( Tuple::new_parenthesized(value) ),* ) )
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Impl(u32);
+
+    impl Construct for Impl {
+        fn new(value: u32) -> Self {
+            Impl(value)
+        }
+
+        fn new_parenthesized(value: u32) -> Self {
+            Impl(value)
+        }
+    }
+
+    <()>::new(0);
+    assert_eq!((Impl(1),), <(Impl,)>::new(1));
+    assert_eq!((Impl(1),), <(Impl,)>::new_parenthesized(1));
+    assert_eq!((Impl(1), Impl(1), Impl(1)), <(Impl, Impl, Impl)>::new(1));
+}
+
+#[test]
+fn semi_automatic_unsafe_trait() {
+    /// # Safety
+    ///
+    /// Nothing to uphold, the trait only checks that `unsafe` is kept.
+    unsafe trait Trait {
+        type A;
     }
 
     #[impl_for_tuples(5)]
@@ -649,3 +1485,620 @@ fn semi_automatic_unsafe_trait() {
         for_tuples!( type A = ( #( Tuple::A ),* ); );
     }
 }
+
+#[test]
+fn recursive_impls() {
+    #[impl_for_tuples(recursive)]
+    trait Visit {
+        fn visit(&self, visited: &mut Vec<u32>);
+    }
+
+    impl Visit for u32 {
+        fn visit(&self, visited: &mut Vec<u32>) {
+            visited.push(*self);
+        }
+    }
+
+    trait Count {
+        const COUNT: usize;
+        type Values;
+    }
+
+    impl Count for u32 {
+        const COUNT: usize = 1;
+        type Values = u32;
+    }
+
+    #[impl_for_tuples(recursive)]
+    impl Count for Tuple {
+        for_tuples!( const COUNT: usize = 0 #( + Tuple::COUNT )*; );
+        for_tuples!( type Values = ( #( Tuple::Values, )* ); );
+    }
+
+    let mut visited = Vec::new();
+    (1u32, (2u32, (3u32, ()))).visit(&mut visited);
+    assert_eq!(vec![1, 2, 3], visited);
+
+    assert_eq!(4, <(u32, (u32, (u32, (u32, ()))))>::COUNT);
+    let _: <(u32, (u32, ())) as Count>::Values = (1, (2, ()));
+
+    // Builds the nested tuple `(a, (b, (c, ())))` for the given values.
+    macro_rules! cons {
+        () => { () };
+        ($head:expr $(, $tail:expr)*) => { ($head, cons!($( $tail ),*)) };
+    }
+
+    fn count_of<T: Count>(_: &T) -> usize {
+        T::COUNT
+    }
+
+    // More elements than the std implementations support for flat tuples.
+    let values = cons!(
+        1u32, 2u32, 3u32, 4u32, 5u32, 6u32, 7u32, 8u32, 9u32, 10u32, 11u32, 12u32, 13u32, 14u32,
+        15u32, 16u32, 17u32, 18u32, 19u32, 20u32, 21u32, 22u32, 23u32, 24u32, 25u32, 26u32, 27u32,
+        28u32, 29u32, 30u32, 31u32, 32u32
+    );
+    let mut visited = Vec::new();
+    values.visit(&mut visited);
+    assert_eq!((1..=32).collect::<Vec<_>>(), visited);
+    assert_eq!(32, count_of(&values));
+}
+
+#[test]
+fn nested_tuples_beyond_twelve_elements() {
+    #[impl_for_tuples(24, nested)]
+    trait Visit: std::fmt::Debug + Default + PartialEq {
+        fn visit(&self, visited: &mut Vec<u32>);
+    }
+
+    impl Visit for u32 {
+        fn visit(&self, visited: &mut Vec<u32>) {
+            visited.push(*self);
+        }
+    }
+
+    trait Count {
+        const COUNT: usize;
+    }
+
+    impl Count for u32 {
+        const COUNT: usize = 1;
+    }
+
+    #[impl_for_tuples(24, nested)]
+    impl Count for Tuple
+    where
+        Self: Default,
+    {
+        for_tuples!( const COUNT: usize = 0 #( + Tuple::COUNT )*; );
+        for_tuples!( where Self: PartialEq );
+    }
+
+    type Values = nested!(
+        u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32,
+        u32, u32
+    );
+
+    // The elements are grouped into tuples with up to 12 elements.
+    let values: Values = nested!(
+        1u32, 2u32, 3u32, 4u32, 5u32, 6u32, 7u32, 8u32, 9u32, 10u32, 11u32, 12u32, 13u32, 14u32,
+        15u32, 16u32, 17u32, 18u32, 19u32, 20u32
+    );
+    assert_eq!(12, (values.0).11);
+    assert_eq!(13, (values.1).0);
+
+    let mut visited = Vec::new();
+    values.visit(&mut visited);
+    assert_eq!((1..=20).collect::<Vec<_>>(), visited);
+    assert_eq!(20, <Values as Count>::COUNT);
+
+    assert_ne!(Values::default(), values);
+    assert!(format!("{:?}", values).starts_with("((1, 2, 3"));
+
+    let nested!(v1, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, v20) = values;
+    assert_eq!((1, 20), (v1, v20));
+
+    // Up to 12 elements, the tuple is not nested.
+    let flat: nested!(u32, u32) = nested!(1, 2);
+    assert_eq!((1, 2), flat);
+    assert_eq!((1,), nested!(1));
+    assert_eq!((), nested!());
+}
+
+#[test]
+fn semi_automatic_also_arrays() {
+    trait Weight {
+        type Each;
+
+        fn weight(&self) -> u32;
+        fn each(&self) -> Self::Each;
+        fn doubled(&self) -> Self;
+        fn into_weight(self) -> u32;
+    }
+
+    impl Weight for u32 {
+        type Each = u32;
+
+        fn weight(&self) -> u32 {
+            *self
+        }
+
+        fn each(&self) -> u32 {
+            *self
+        }
+
+        fn doubled(&self) -> Self {
+            *self * 2
+        }
+
+        fn into_weight(self) -> u32 {
+            self
+        }
+    }
+
+    #[impl_for_tuples(1, 3, also_arrays)]
+    impl Weight for Tuple {
+        for_tuples!( type Each = ( #( Tuple::Each ),* ); );
+
+        fn weight(&self) -> u32 {
+            let mut weight = 0;
+            for_tuples!( #( let Tuple_weight = self.Tuple.weight(); )* );
+            for_tuples!( #( weight += Tuple_weight; )* );
+            weight
+        }
+
+        fn each(&self) -> Self::Each {
+            for_tuples!( ( #( Tuple.each() ),* ) )
+        }
+
+        fn doubled(&self) -> Self {
+            for_tuples!( Self( #( self.Tuple.doubled() )* ) )
+        }
+
+        fn into_weight(self) -> u32 {
+            let for_tuples!( ( #( Tuple ),* ) ) = self;
+            let mut weight = 0;
+            for_tuples!( #( weight += Weight::into_weight(Tuple); )* );
+            weight
+        }
+    }
+
+    assert_eq!(6, (1u32, 2u32, 3u32).weight());
+    assert_eq!(6, [1u32, 2, 3].weight());
+    assert_eq!(3, [3u32].weight());
+    assert_eq!((1, 2), [1u32, 2].each());
+    assert_eq!([2, 4, 6], [1u32, 2, 3].doubled());
+    assert_eq!(6, [1u32, 2, 3].into_weight());
+    assert_eq!(6, (1u32, 2u32, 3u32).into_weight());
+}
+
+#[test]
+fn full_automatic_dyn_views() {
+    #[impl_for_tuples(5, dyn_views)]
+    trait Plugin {
+        fn load(&mut self);
+        fn report(&self, loaded: &mut Vec<bool>);
+    }
+
+    #[derive(Default)]
+    struct Lazy(bool);
+
+    impl Plugin for Lazy {
+        fn load(&mut self) {
+            self.0 = true;
+        }
+
+        fn report(&self, loaded: &mut Vec<bool>) {
+            loaded.push(self.0);
+        }
+    }
+
+    struct Eager;
+
+    impl Plugin for Eager {
+        fn load(&mut self) {}
+
+        fn report(&self, loaded: &mut Vec<bool>) {
+            loaded.push(true);
+        }
+    }
+
+    let mut plugins = (Lazy::default(), Eager, Lazy::default());
+    assert_eq!(3, <(Lazy, Eager, Lazy) as PluginAsDyn>::LEN);
+
+    let mut loaded = Vec::new();
+    plugins
+        .as_dyn()
+        .into_iter()
+        .for_each(|p| p.report(&mut loaded));
+    assert_eq!(vec![false, true, false], loaded);
+
+    plugins.as_dyn_mut()[2].load();
+    let mut loaded = Vec::new();
+    plugins.as_dyn()[2].report(&mut loaded);
+    assert_eq!(vec![true], loaded);
+    assert!(().as_dyn().is_empty());
+}
+
+#[test]
+fn full_automatic_enum_dispatch() {
+    #[impl_for_tuples(3, enum_dispatch)]
+    trait Handle {
+        fn handle(&self, handled: &mut Vec<String>, event: &str);
+        fn reset(&mut self);
+    }
+
+    struct Named(&'static str, u32);
+
+    impl Handle for Named {
+        fn handle(&self, handled: &mut Vec<String>, event: &str) {
+            handled.push(format!("{}: {}", self.0, event));
+        }
+
+        fn reset(&mut self) {
+            self.1 = 0;
+        }
+    }
+
+    impl Handle for u32 {
+        fn handle(&self, handled: &mut Vec<String>, event: &str) {
+            handled.push(format!("{}: {}", self, event));
+        }
+
+        fn reset(&mut self) {
+            *self = 0;
+        }
+    }
+
+    let mut handled = Vec::new();
+    let tuple = (Named("first", 1), 2u32);
+    tuple.handle(&mut handled, "all");
+    assert_eq!(vec!["first: all", "2: all"], handled);
+
+    let mut handled = Vec::new();
+    let one: HandleEnum2<Named, u32> = HandleEnum2::Element1(3);
+    one.handle(&mut handled, "one");
+    HandleEnum3::<u32, Named, u32>::Element1(Named("second", 1)).handle(&mut handled, "one");
+    assert_eq!(vec!["3: one", "second: one"], handled);
+
+    let mut one = HandleEnum1::<u32>::Element0(5);
+    one.reset();
+    assert!(matches!(one, HandleEnum1::Element0(0)));
+}
+
+#[test]
+fn full_automatic_enum_dispatch_without_clone_arguments() {
+    struct Event(u32);
+
+    #[impl_for_tuples(2, enum_dispatch)]
+    trait Consume<E> {
+        fn consume(&self, event: E, consumed: &mut Vec<u32>);
+    }
+
+    impl Consume<Event> for u32 {
+        fn consume(&self, event: Event, consumed: &mut Vec<u32>) {
+            consumed.push(self + event.0);
+        }
+    }
+
+    let mut consumed = Vec::new();
+    ConsumeEnum2::<u32, u32>::Element1(1).consume(Event(2), &mut consumed);
+    assert_eq!(vec![3], consumed);
+}
+
+#[test]
+fn tuple_visit_generates_visitors() {
+    #[tuple_visit(5)]
+    trait Named {
+        fn name(&self) -> String;
+        fn rename(&mut self, name: &str);
+    }
+
+    #[derive(Default)]
+    struct Plugin(String);
+
+    impl Named for Plugin {
+        fn name(&self) -> String {
+            self.0.clone()
+        }
+
+        fn rename(&mut self, name: &str) {
+            self.0 = name.into();
+        }
+    }
+
+    impl Named for u32 {
+        fn name(&self) -> String {
+            self.to_string()
+        }
+
+        fn rename(&mut self, name: &str) {
+            *self = name.len() as u32;
+        }
+    }
+
+    #[derive(Default)]
+    struct Names(Vec<String>);
+
+    impl NamedVisitor for Names {
+        fn visit<T: Named>(&mut self, element: &T) {
+            self.0.push(element.name());
+        }
+    }
+
+    struct Rename(&'static str);
+
+    impl NamedVisitor for Rename {
+        fn visit<T: Named>(&mut self, _: &T) {}
+
+        fn visit_mut<T: Named>(&mut self, element: &mut T) {
+            element.rename(self.0);
+        }
+    }
+
+    let mut tuple = (Plugin("first".into()), 2u32);
+    let mut names = Names::default();
+    tuple.visit_each(&mut names);
+    assert_eq!(vec!["first", "2"], names.0);
+
+    tuple.visit_each_mut(&mut Rename("renamed"));
+    let mut names = Names::default();
+    tuple.visit_each_owned(&mut names);
+    assert_eq!(vec!["renamed", "7"], names.0);
+
+    // The visitor is not called for the empty tuple.
+    let mut names = Names::default();
+    ().visit_each(&mut names);
+    ().visit_each_mut(&mut Rename("renamed"));
+    ().visit_each_owned(&mut names);
+    assert!(names.0.is_empty());
+}
+
+#[test]
+fn tuple_visit_with_min_elements() {
+    #[tuple_visit(2, 4)]
+    trait Weight {
+        fn weight(&self) -> u32;
+    }
+
+    impl Weight for u32 {
+        fn weight(&self) -> u32 {
+            *self
+        }
+    }
+
+    struct Sum(u32);
+
+    impl WeightVisitor for Sum {
+        fn visit<T: Weight>(&mut self, element: &T) {
+            self.0 += element.weight();
+        }
+    }
+
+    let mut sum = Sum(0);
+    (1u32, 2u32).visit_each(&mut sum);
+    (3u32, 4u32, 5u32, 6u32).visit_each_owned(&mut sum);
+    assert_eq!(21, sum.0);
+}
+
+#[test]
+fn tuple_items_per_arity() {
+    trait Len {
+        const LEN: usize;
+    }
+
+    tuple_items!(1..=4, |Tuple| {
+        type #ident(Boxed #arity)<#( Tuple ),*> = ( #( Box<Tuple> ),* );
+
+        fn #ident(boxed_ #arity)<#( Tuple ),*>(tuple: Tuple) -> #ident(Boxed #arity)<#( Tuple ),*> {
+            ( #( Box::new(tuple.Tuple), )* )
+        }
+
+        impl<#( Tuple ),*> Len for Tuple {
+            const LEN: usize = #arity;
+        }
+    });
+
+    tuple_items!(1..3, |T| {
+        fn #ident(first_ #arity)<#( T: Copy ),*>(tuple: &T) -> u32 where #( T: Into<u32>, )* {
+            tuple.0.into()
+        }
+    });
+
+    // Idents that contain the placeholder are kept as they are.
+    tuple_items!(1..=1, |T| {
+        const MAX_COUNT: usize = 3;
+        struct LimiT;
+        const COUNT_T: usize = 1;
+    });
+
+    let boxed: Boxed2<u32, u8> = boxed_2((1, 2));
+    assert_eq!((Box::new(1), Box::new(2)), boxed);
+    assert_eq!((Box::new(1),), boxed_1((1,)));
+    let boxed: Boxed3<u8, u8, u8> = boxed_3((1, 2, 3));
+    assert_eq!(Box::new(3), boxed.2);
+    let boxed: Boxed4<u8, u8, u8, u8> = boxed_4((1, 2, 3, 4));
+    assert_eq!(Box::new(4), boxed.3);
+    assert_eq!(3, <(u8, u8, u8)>::LEN);
+    assert_eq!(1, <(u8,)>::LEN);
+    assert_eq!(5, first_1(&(5u8,)));
+    assert_eq!(5, first_2(&(5u8, 6u16)));
+    assert_eq!(3, MAX_COUNT);
+    assert_eq!(1, COUNT_T);
+    let _ = LimiT;
+}
+
+trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+trait Decode: Sized {
+    fn decode(input: &mut &[u8]) -> Option<Self>;
+}
+
+impl Encode for u8 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+}
+
+impl Decode for u8 {
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let (first, rest) = input.split_first()?;
+        *input = rest;
+        Some(*first)
+    }
+}
+
+#[impl_for_tuples(5)]
+mod codec_tuple_impls {
+    use super::{Decode, Encode};
+
+    impl Encode for Tuple {
+        fn encode(&self, out: &mut Vec<u8>) {
+            for_tuples!( #( Tuple.encode(out); )* );
+        }
+    }
+
+    impl Decode for Tuple {
+        fn decode(input: &mut &[u8]) -> Option<Self> {
+            Some(for_tuples!( Self( #( Tuple::decode(input)? )* ) ))
+        }
+    }
+
+    // Implementations for types of the module are not expanded.
+    pub struct Counter(pub usize);
+
+    impl Counter {
+        pub fn count<T: Encode>(&mut self, value: &T) {
+            let mut out = Vec::new();
+            value.encode(&mut out);
+            self.0 += out.len();
+        }
+    }
+
+    impl Encode for Counter {
+        fn encode(&self, out: &mut Vec<u8>) {
+            out.push(self.0 as u8);
+        }
+    }
+}
+
+#[test]
+fn semi_automatic_impls_in_module() {
+    let mut out = Vec::new();
+    (1u8, 2u8, 3u8).encode(&mut out);
+    assert_eq!(vec![1, 2, 3], out);
+
+    assert_eq!(Some((1u8, 2u8, 3u8)), Decode::decode(&mut &out[..]));
+    assert_eq!(None, <(u8, u8, u8, u8)>::decode(&mut &out[..]));
+
+    let mut counter = codec_tuple_impls::Counter(0);
+    counter.count(&(1u8, 2u8));
+    counter.count(&(7u8,));
+    assert_eq!(3, counter.0);
+    (counter, 4u8).encode(&mut out);
+    assert_eq!(vec![1, 2, 3, 3, 4], out);
+}
+
+#[derive_fieldwise(
+    impl Encode for Fields {
+        fn encode(&self, out: &mut Vec<u8>) {
+            for_tuples!( #( self.Fields.encode(out); )* );
+        }
+    }
+
+    impl Decode for Fields {
+        fn decode(input: &mut &[u8]) -> Option<Self> {
+            Some(for_tuples!( Self( #( Fields::decode(input)? )* ) ))
+        }
+    }
+)]
+#[derive(Debug, PartialEq)]
+struct Header {
+    version: u8,
+    flags: (u8, u8),
+}
+
+#[derive_fieldwise(
+    impl Encode for Fields {
+        fn encode(&self, out: &mut Vec<u8>) {
+            for_tuples!( #( Fields.encode(out); )* );
+        }
+    }
+)]
+struct Pair(u8, Header);
+
+#[test]
+fn derive_fieldwise_for_structs() {
+    let header = Header {
+        version: 1,
+        flags: (2, 3),
+    };
+
+    let mut out = Vec::new();
+    header.encode(&mut out);
+    assert_eq!(vec![1, 2, 3], out);
+    assert_eq!(Some(header), Header::decode(&mut &out[..]));
+
+    let mut out = Vec::new();
+    Pair(
+        0,
+        Header {
+            version: 1,
+            flags: (2, 3),
+        },
+    )
+    .encode(&mut out);
+    assert_eq!(vec![0, 1, 2, 3], out);
+}
+
+#[test]
+fn derive_fieldwise_destructuring_patterns() {
+    trait Sum {
+        fn sum(self) -> u32;
+    }
+
+    impl Sum for u32 {
+        fn sum(self) -> u32 {
+            self
+        }
+    }
+
+    #[derive_fieldwise(
+        impl Sum for Fields {
+            fn sum(self) -> u32 {
+                let for_tuples!( ( #( Fields ),* ) ) = self;
+                let mut sum = 0;
+                for_tuples!( #( sum += Sum::sum(Fields); )* );
+                sum
+            }
+        }
+    )]
+    struct Values {
+        first: u32,
+        second: u32,
+    }
+
+    #[derive_fieldwise(
+        impl Sum for Fields {
+            fn sum(self) -> u32 {
+                let for_tuples!( ( #( Fields ),* ) ) = self;
+                let mut sum = 0;
+                for_tuples!( #( sum += Sum::sum(Fields); )* );
+                sum
+            }
+        }
+    )]
+    struct Pair(u32, u32);
+
+    assert_eq!(
+        3,
+        Values {
+            first: 1,
+            second: 2
+        }
+        .sum()
+    );
+    assert_eq!(7, Pair(3, 4).sum());
+}
@@ -0,0 +1,13 @@
+trait Test {
+    fn test(&self);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5, also_arrays)]
+#[tuple_types_custom_trait_bound(Test, last: Test + Default)]
+impl Test for Tuple {
+    fn test(&self) {
+        for_tuples!( #( self.Tuple.test(); )* );
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test();
+}
+
+struct Wrapper<A, B>(A, B);
+
+#[impl_for_tuples(2)]
+impl Test for Wrapper<String, Elements> {
+    fn test() {}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test();
+}
+
+#[impl_for_tuples(3)]
+#[tuple_types_custom_trait_bound(0: Test + Default, last: ?Sized + Test)]
+impl Test for Tuple {
+    fn test() {}
+}
+
+fn main() {}
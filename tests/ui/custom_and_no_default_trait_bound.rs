@@ -0,0 +1,14 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test();
+}
+
+#[impl_for_tuples(2)]
+#[tuple_types_no_default_trait_bound]
+#[tuple_types_custom_trait_bound(Clone)]
+impl Test for Tuple {
+    fn test() {}
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use impl_trait_for_tuples::derive_fieldwise;
+
+trait Test {
+    fn test(&self);
+}
+
+#[derive_fieldwise(
+    impl Test for Fields {
+        fn test(&self) {
+            for_tuples!( #( self.Fields.test(); )* );
+        }
+    }
+)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {}
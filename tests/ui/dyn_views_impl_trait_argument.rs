@@ -0,0 +1,6 @@
+#[impl_trait_for_tuples::impl_for_tuples(5, dyn_views)]
+trait Test {
+    fn test(&self, value: impl std::fmt::Debug);
+}
+
+fn main() {}
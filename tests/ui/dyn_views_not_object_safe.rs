@@ -0,0 +1,11 @@
+#[impl_trait_for_tuples::impl_for_tuples(5, dyn_views)]
+trait Test {
+    fn generic<T>(&self, t: T);
+    fn compare(&self, other: &Self);
+    fn create();
+    fn sized_only<T>(&self, t: T)
+    where
+        Self: Sized;
+}
+
+fn main() {}
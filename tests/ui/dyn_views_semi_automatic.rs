@@ -0,0 +1,12 @@
+trait Test {
+    fn test(&self);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5, dyn_views)]
+impl Test for Tuple {
+    fn test(&self) {
+        for_tuples!( #( self.Tuple.test(); )* );
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[impl_trait_for_tuples::impl_for_tuples(5, dyn_views)]
+trait Test
+where
+    Self: Sized,
+{
+    fn test(&self);
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[impl_trait_for_tuples::impl_for_tuples(5, enum_dispatch)]
+trait Test {
+    fn test(&self);
+    fn create();
+}
+
+fn main() {}
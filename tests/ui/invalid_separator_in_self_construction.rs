@@ -0,0 +1,14 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test() -> Self;
+}
+
+#[impl_for_tuples(2)]
+impl Test for Tuple {
+    fn test() -> Self {
+        for_tuples!( Self( #( Tuple::test() )+* ) )
+    }
+}
+
+fn main() {}
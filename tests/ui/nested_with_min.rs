@@ -0,0 +1,6 @@
+#[impl_trait_for_tuples::impl_for_tuples(2, 24, nested)]
+trait Test {
+    fn test(&self);
+}
+
+fn main() {}
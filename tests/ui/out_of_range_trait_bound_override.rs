@@ -0,0 +1,13 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test();
+}
+
+#[impl_for_tuples(3)]
+#[tuple_types_custom_trait_bound(Test, 3: Test + Default)]
+impl Test for Tuple {
+    fn test() {}
+}
+
+fn main() {}
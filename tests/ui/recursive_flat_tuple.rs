@@ -0,0 +1,13 @@
+#[impl_trait_for_tuples::impl_for_tuples(recursive)]
+trait Test {
+    fn test(&self);
+}
+
+impl Test for u32 {
+    fn test(&self) {}
+}
+
+fn main() {
+    (1u32, (2u32, (3u32, ()))).test();
+    (1u32, 2u32, 3u32).test();
+}
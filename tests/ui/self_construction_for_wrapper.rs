@@ -0,0 +1,13 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+struct Wrapper<T>(T);
+
+#[impl_for_tuples(2)]
+#[tuple_types_custom_trait_bound(Default)]
+impl Wrapper<Tuple> {
+    fn new() -> Self {
+        Wrapper(for_tuples!( Self( #( Tuple::default() )* ) ))
+    }
+}
+
+fn main() {}
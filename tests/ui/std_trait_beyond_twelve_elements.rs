@@ -0,0 +1,6 @@
+#[impl_trait_for_tuples::impl_for_tuples(16)]
+trait Test: std::fmt::Debug + PartialEq {
+    fn test(&self);
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+trait Test {
+    fn test(&self);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(13)]
+impl Test for Tuple {
+    for_tuples!( where Self: std::fmt::Debug );
+
+    fn test(&self) {
+        for_tuples!( #( self.Tuple.test(); )* );
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+mod my {
+    pub trait Hash {}
+}
+
+// Only `std::hash::Hash` is rejected, while the local `my::Hash` is accepted.
+#[impl_trait_for_tuples::impl_for_tuples(13)]
+trait Digest: my::Hash + std::hash::Hash {
+    fn digest(&self);
+}
+
+fn main() {}
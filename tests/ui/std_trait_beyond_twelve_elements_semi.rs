@@ -0,0 +1,15 @@
+trait Test {
+    fn test(&self);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(13)]
+impl Test for Tuple
+where
+    Self: Default,
+{
+    fn test(&self) {
+        for_tuples!( #( self.Tuple.test(); )* );
+    }
+}
+
+fn main() {}
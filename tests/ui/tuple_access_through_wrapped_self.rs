@@ -0,0 +1,18 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Count {
+    fn count(&self) -> u32;
+}
+
+struct Wrapper<T>(T);
+
+#[impl_for_tuples(2)]
+impl Count for Wrapper<Tuple> {
+    fn count(&self) -> u32 {
+        let mut count = 0;
+        for_tuples!( #( count += Tuple.count(); )* );
+        count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use impl_trait_for_tuples::tuple_items;
+
+tuple_items!(3..=1, |Tuple| {
+    fn test() {}
+});
+
+fn main() {}
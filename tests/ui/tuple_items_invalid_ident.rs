@@ -0,0 +1,7 @@
+use impl_trait_for_tuples::tuple_items;
+
+tuple_items!(1..=2, |Tuple| {
+    fn #ident(#arity _first)() {}
+});
+
+fn main() {}
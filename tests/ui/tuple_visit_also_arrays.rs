@@ -0,0 +1,6 @@
+#[impl_trait_for_tuples::tuple_visit(5, also_arrays)]
+trait Named {
+    fn name(&self) -> String;
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use impl_trait_for_tuples::tuple_visit;
+
+#[tuple_visit(2, 4)]
+trait Weight {
+    fn weight(&self) -> u32;
+}
+
+impl Weight for u32 {
+    fn weight(&self) -> u32 {
+        *self
+    }
+}
+
+struct Sum(u32);
+
+impl WeightVisitor for Sum {
+    fn visit<T: Weight>(&mut self, element: &T) {
+        self.0 += element.weight();
+    }
+}
+
+fn main() {
+    (1u32,).visit_each(&mut Sum(0));
+}
@@ -0,0 +1,12 @@
+use impl_trait_for_tuples::impl_for_tuples;
+
+trait Test {
+    fn test();
+}
+
+#[impl_for_tuples(2)]
+impl Test for for_tuples!( #( Option<Tuple> ),* ) {
+    fn test() {}
+}
+
+fn main() {}
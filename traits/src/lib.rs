@@ -0,0 +1,226 @@
+/*!
+Common tuple traits implemented with [`impl-trait-for-tuples`](https://docs.rs/impl-trait-for-tuples).
+
+The crate is `no_std` compatible and serves as ready to use toolkit and as a showcase of the macros.
+By default the traits are implemented for tuples with up to 12 elements. This can be increased to
+16 or 32 elements by enabling the `arity-16` or `arity-32` feature.
+
+```
+use impl_trait_for_tuples_traits::{Mapper, TupleConcat, TupleLen, TupleMap, TuplePush, TupleReverse};
+
+struct Double;
+
+impl Mapper<u32> for Double {
+    type Output = u32;
+
+    fn map(&mut self, value: u32) -> u32 {
+        value * 2
+    }
+}
+
+impl Mapper<&'static str> for Double {
+    type Output = usize;
+
+    fn map(&mut self, value: &'static str) -> usize {
+        value.len() * 2
+    }
+}
+
+assert_eq!(3, (1, 2, 3).len());
+assert_eq!((2, 6), (1u32, "abc").map(&mut Double));
+assert_eq!((1, 2, 3), (1, 2).push(3));
+assert_eq!((1, 2, 3, 4), (1, 2).concat((3, 4)));
+assert_eq!((3, 2, 1), (1, 2, 3).reverse());
+```
+*/
+
+#![no_std]
+
+use impl_trait_for_tuples::{impl_for_tuples, tuple_items};
+
+/// Provides the number of elements of a tuple.
+pub trait TupleLen {
+    /// The number of elements.
+    const LEN: usize;
+
+    /// Returns the number of elements.
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns `true` if the tuple has no elements.
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
+
+/// Maps a value of type `T` to [`Self::Output`].
+///
+/// Used by [`TupleMap`] to map each element of a tuple.
+pub trait Mapper<T> {
+    /// The mapped value.
+    type Output;
+
+    /// Map the given `value`.
+    fn map(&mut self, value: T) -> Self::Output;
+}
+
+/// Maps each element of a tuple with the mapper `M`.
+pub trait TupleMap<M> {
+    /// The tuple of the mapped elements.
+    type Output;
+
+    /// Map each element of `self` with the given `mapper`, starting with the first element.
+    fn map(self, mapper: &mut M) -> Self::Output;
+}
+
+/// Inspects a reference to a value of type `T`.
+///
+/// Used by [`TupleForEach`] to inspect each element of a tuple.
+pub trait Inspector<T: ?Sized> {
+    /// Inspect the given `value`.
+    fn inspect(&mut self, value: &T);
+}
+
+/// Calls the inspector `I` for each element of a tuple.
+pub trait TupleForEach<I> {
+    /// Call the given `inspector` for each element of `self`, starting with the first element.
+    fn for_each(&self, inspector: &mut I);
+}
+
+/// Appends a value of type `T` to a tuple.
+pub trait TuplePush<T> {
+    /// The tuple with the appended value.
+    type Output;
+
+    /// Append `value` to `self`.
+    fn push(self, value: T) -> Self::Output;
+}
+
+/// Appends all elements of the tuple `Other` to a tuple.
+///
+/// Only tuples can be concatenated:
+///
+/// ```compile_fail
+/// use impl_trait_for_tuples_traits::TupleConcat;
+///
+/// let _ = 1u32.concat(());
+/// ```
+pub trait TupleConcat<Other> {
+    /// The concatenated tuple.
+    type Output;
+
+    /// Append all elements of `other` to `self`.
+    fn concat(self, other: Other) -> Self::Output;
+}
+
+/// Reverses the order of the elements of a tuple.
+pub trait TupleReverse {
+    /// The reversed tuple.
+    type Output;
+
+    /// Reverse the order of the elements of `self`.
+    fn reverse(self) -> Self::Output;
+}
+
+impl TupleReverse for () {
+    type Output = ();
+
+    fn reverse(self) -> Self::Output {}
+}
+
+/// Implements the traits for tuples with up to `$max` elements.
+macro_rules! tuple_impls {
+    ($max:tt) => {
+        tuple_items!(0..=$max, |Tuple| {
+            impl<#( Tuple ),*> TupleLen for Tuple {
+                const LEN: usize = #arity;
+            }
+        });
+
+        #[impl_for_tuples($max)]
+        #[tuple_types_no_default_trait_bound]
+        impl<M> TupleMap<M> for Tuple {
+            for_tuples!( where #( M: Mapper<Tuple> )* );
+            for_tuples!( type Output = ( #( <M as Mapper<Tuple>>::Output, )* ); );
+
+            fn map(self, mapper: &mut M) -> Self::Output {
+                for_tuples!( ( #( <M as Mapper<Tuple>>::map(mapper, self.Tuple) ),* ) )
+            }
+        }
+
+        #[impl_for_tuples($max)]
+        #[tuple_types_no_default_trait_bound]
+        impl<I> TupleForEach<I> for Tuple {
+            for_tuples!( where #( I: Inspector<Tuple> )* );
+
+            fn for_each(&self, inspector: &mut I) {
+                for_tuples!( #( <I as Inspector<Tuple>>::inspect(inspector, &self.Tuple); )* );
+            }
+        }
+
+        // The pushed value is the last element, so the tuple needs to have less than `$max`
+        // elements.
+        tuple_items!(0..$max, |Tuple| {
+            impl<#( Tuple, )* T> TuplePush<T> for Tuple {
+                type Output = ( #( Tuple, )* T, );
+
+                fn push(self, value: T) -> Self::Output {
+                    ( #( self.Tuple, )* value, )
+                }
+            }
+        });
+
+        // Concatenating `()` ends the recursion below, only tuples can be concatenated.
+        tuple_items!(0..=$max, |Tuple| {
+            impl<#( Tuple ),*> TupleConcat<()> for Tuple {
+                type Output = Self;
+
+                fn concat(self, _: ()) -> Self {
+                    self
+                }
+            }
+        });
+
+        // `Other` is split into its first elements and its last element, the first elements are
+        // concatenated recursively before the last element is pushed.
+        tuple_items!(0..$max, |Tuple| {
+            impl<S, #( Tuple, )* Last> TupleConcat<( #( Tuple, )* Last, )> for S
+            where
+                S: TupleConcat<Tuple>,
+                <S as TupleConcat<Tuple>>::Output: TuplePush<Last>,
+            {
+                type Output = <<S as TupleConcat<Tuple>>::Output as TuplePush<Last>>::Output;
+
+                fn concat(self, other: ( #( Tuple, )* Last, )) -> Self::Output {
+                    <S as TupleConcat<Tuple>>::concat(self, ( #( other.Tuple, )* ))
+                        .push(other.#arity)
+                }
+            }
+        });
+
+        // The last element comes first, followed by the reversed first elements.
+        tuple_items!(0..$max, |Tuple| {
+            impl<#( Tuple, )* Last> TupleReverse for ( #( Tuple, )* Last, )
+            where
+                Tuple: TupleReverse,
+                (Last,): TupleConcat<<Tuple as TupleReverse>::Output>,
+            {
+                type Output = <(Last,) as TupleConcat<<Tuple as TupleReverse>::Output>>::Output;
+
+                fn reverse(self) -> Self::Output {
+                    (self.#arity,).concat(( #( self.Tuple, )* ).reverse())
+                }
+            }
+        });
+    };
+}
+
+#[cfg(not(feature = "arity-16"))]
+tuple_impls!(12);
+
+#[cfg(all(feature = "arity-16", not(feature = "arity-32")))]
+tuple_impls!(16);
+
+#[cfg(feature = "arity-32")]
+tuple_impls!(32);
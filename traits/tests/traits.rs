@@ -0,0 +1,62 @@
+use impl_trait_for_tuples_traits::{
+    Inspector, Mapper, TupleConcat, TupleForEach, TupleLen, TupleMap, TuplePush, TupleReverse,
+};
+
+#[test]
+fn tuple_len() {
+    assert_eq!(0, <()>::LEN);
+    assert!(().is_empty());
+    assert_eq!(1, (1,).len());
+    assert_eq!(12, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11).len());
+}
+
+#[test]
+fn tuple_map() {
+    struct ToString;
+
+    impl<T: std::fmt::Display> Mapper<T> for ToString {
+        type Output = String;
+
+        fn map(&mut self, value: T) -> String {
+            value.to_string()
+        }
+    }
+
+    assert_eq!(
+        ("1".to_string(), "a".to_string(), "true".to_string()),
+        (1, 'a', true).map(&mut ToString),
+    );
+}
+
+#[test]
+fn tuple_for_each() {
+    struct Collect(Vec<String>);
+
+    impl<T: std::fmt::Debug> Inspector<T> for Collect {
+        fn inspect(&mut self, value: &T) {
+            self.0.push(format!("{:?}", value));
+        }
+    }
+
+    let mut collect = Collect(Vec::new());
+    (1u8, "two", Some(3)).for_each(&mut collect);
+    assert_eq!(vec!["1", "\"two\"", "Some(3)"], collect.0);
+}
+
+#[test]
+fn tuple_push_concat_and_reverse() {
+    assert_eq!((1,), ().push(1));
+    assert_eq!((1, "b"), (1,).push("b"));
+
+    assert_eq!((1, 2), (1, 2).concat(()));
+    assert_eq!((1, 'b', "c"), ().concat((1, 'b', "c")));
+    assert_eq!((1, 'b', "c", 4u8), (1, 'b').concat(("c", 4u8)));
+
+    assert_eq!((), ().reverse());
+    assert_eq!((1,), (1,).reverse());
+    assert_eq!(("c", 'b', 1), (1, 'b', "c").reverse());
+    assert_eq!(
+        (11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0),
+        (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11).reverse(),
+    );
+}